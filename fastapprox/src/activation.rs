@@ -0,0 +1,271 @@
+//! Functions with a `_with_grad` suffix return the value together with the derivative,
+//! sharing the expensive intermediate results between the two, and functions with a `_slice`
+//! suffix apply the same activation to a batch of inputs.
+//!
+//! Unlike the raw `exp`, these are safe to use over the whole range of `f32`:
+//! arguments of the underlying approximations are clamped to the range where they do not
+//! overflow.
+
+macro_rules! slice_impl {
+    ($(#[$doc:meta])* $name:ident => $f:ident $(, $param:ident)*) => {
+        $(#[$doc])*
+        #[inline]
+        pub fn $name(xs: &[f32], $($param: f32,)* out: &mut [f32]) {
+            assert_eq!(xs.len(), out.len());
+            for (y, &x) in out.iter_mut().zip(xs) {
+                *y = $f(x $(, $param)*);
+            }
+        }
+    };
+}
+
+macro_rules! activation_impl {
+    ($tier:ident) => {
+        use crate::$tier::{erf, exp, ln, sigmoid, tanh};
+
+        const INVSQRT2PI: f32 = 0.3989423;
+
+        /// Largest argument of the exponential which does not overflow.
+        const MAX_EXP_ARG: f32 = 88.0;
+
+        /// Arguments beyond which `erf` and `tanh` are ±1 in single precision.
+        const MAX_ERF_ARG: f32 = 8.0;
+        const MAX_TANH_ARG: f32 = 9.0;
+
+        /// Scale and shift of SELU from Klambauer et al.
+        const SELU_ALPHA: f32 = 1.6732632;
+        const SELU_LAMBDA: f32 = 1.050701;
+
+        /// Softplus function `ln(1 + exp(x))`.
+        #[inline]
+        pub fn softplus(x: f32) -> f32 {
+            if x > 15.0_f32 {
+                x
+            } else if x < -15.0_f32 {
+                exp(x)
+            } else {
+                ln(1.0_f32 + exp(x))
+            }
+        }
+
+        /// Gaussian error linear unit `x * Φ(x)`, using the error function.
+        #[inline]
+        pub fn gelu(x: f32) -> f32 {
+            const INVSQRT2: f32 = std::f32::consts::FRAC_1_SQRT_2;
+            0.5_f32 * x * (1.0_f32 + erf((INVSQRT2 * x).clamp(-MAX_ERF_ARG, MAX_ERF_ARG)))
+        }
+
+        /// Gaussian error linear unit, using the `tanh` formulation from Hendrycks and Gimpel.
+        #[inline]
+        pub fn gelu_tanh(x: f32) -> f32 {
+            const SQRT2OVERPI: f32 = 0.7978846;
+            let p = SQRT2OVERPI * (x + 0.044715_f32 * x * x * x);
+            0.5_f32 * x * (1.0_f32 + tanh(p.clamp(-MAX_TANH_ARG, MAX_TANH_ARG)))
+        }
+
+        /// Sigmoid linear unit `x * sigmoid(x)`.
+        #[inline]
+        pub fn silu(x: f32) -> f32 {
+            x * sigmoid(x.max(-MAX_EXP_ARG))
+        }
+
+        /// Swish function `x * sigmoid(beta * x)`.
+        #[inline]
+        pub fn swish(x: f32, beta: f32) -> f32 {
+            x * sigmoid((beta * x).max(-MAX_EXP_ARG))
+        }
+
+        /// Mish function `x * tanh(softplus(x))`.
+        #[inline]
+        pub fn mish(x: f32) -> f32 {
+            if x > 15.0_f32 {
+                x
+            } else {
+                // tanh(ln(1 + n)) = ((1 + n)² - 1) / ((1 + n)² + 1)
+                let n = exp(x);
+                let m = n * (n + 2.0_f32);
+                x * m / (m + 2.0_f32)
+            }
+        }
+
+        /// Exponential linear unit.
+        #[inline]
+        pub fn elu(x: f32, alpha: f32) -> f32 {
+            if x > 0.0_f32 {
+                x
+            } else {
+                alpha * (exp(x) - 1.0_f32)
+            }
+        }
+
+        /// Scaled exponential linear unit.
+        #[inline]
+        pub fn selu(x: f32) -> f32 {
+            SELU_LAMBDA * elu(x, SELU_ALPHA)
+        }
+
+        /// Softsign function `x / (1 + |x|)`.
+        ///
+        /// Exact, provided for completeness.
+        #[inline]
+        pub fn softsign(x: f32) -> f32 {
+            x / (1.0_f32 + x.abs())
+        }
+
+        /// Sigmoid function and its derivative.
+        #[inline]
+        pub fn sigmoid_with_grad(x: f32) -> (f32, f32) {
+            let s = sigmoid(x.max(-MAX_EXP_ARG));
+            (s, s * (1.0_f32 - s))
+        }
+
+        /// Hyperbolic tangent function and its derivative.
+        #[inline]
+        pub fn tanh_with_grad(x: f32) -> (f32, f32) {
+            let t = tanh(x.clamp(-MAX_TANH_ARG, MAX_TANH_ARG));
+            (t, 1.0_f32 - t * t)
+        }
+
+        /// Softplus function and its derivative.
+        #[inline]
+        pub fn softplus_with_grad(x: f32) -> (f32, f32) {
+            if x > 15.0_f32 {
+                (x, 1.0_f32)
+            } else if x < -15.0_f32 {
+                let e = exp(x);
+                (e, e)
+            } else {
+                let onepe = 1.0_f32 + exp(x);
+                (ln(onepe), 1.0_f32 - 1.0_f32 / onepe)
+            }
+        }
+
+        /// Gaussian error linear unit and its derivative, using the error function.
+        #[inline]
+        pub fn gelu_with_grad(x: f32) -> (f32, f32) {
+            const INVSQRT2: f32 = std::f32::consts::FRAC_1_SQRT_2;
+            let cdf = 0.5_f32 * (1.0_f32 + erf((INVSQRT2 * x).clamp(-MAX_ERF_ARG, MAX_ERF_ARG)));
+            let pdf = INVSQRT2PI * exp((-0.5_f32 * x * x).max(-MAX_EXP_ARG));
+            (x * cdf, cdf + x * pdf)
+        }
+
+        /// Gaussian error linear unit and its derivative, using the `tanh` formulation.
+        #[inline]
+        pub fn gelu_tanh_with_grad(x: f32) -> (f32, f32) {
+            const SQRT2OVERPI: f32 = 0.7978846;
+            let xsq = x * x;
+            let p = SQRT2OVERPI * x * (1.0_f32 + 0.044715_f32 * xsq);
+            // The derivative of p grows as x², so saturation must be exact.
+            let (t, sech2) = if p.abs() < MAX_TANH_ARG {
+                let t = tanh(p);
+                (t, 1.0_f32 - t * t)
+            } else {
+                (p.signum(), 0.0_f32)
+            };
+            let half1pt = 0.5_f32 * (1.0_f32 + t);
+            let dp = SQRT2OVERPI * (1.0_f32 + 0.134145_f32 * xsq);
+            (x * half1pt, half1pt + 0.5_f32 * x * sech2 * dp)
+        }
+
+        /// Sigmoid linear unit and its derivative.
+        #[inline]
+        pub fn silu_with_grad(x: f32) -> (f32, f32) {
+            let s = sigmoid(x.max(-MAX_EXP_ARG));
+            (x * s, s * (1.0_f32 + x * (1.0_f32 - s)))
+        }
+
+        /// Swish function and its derivative with respect to `x`.
+        #[inline]
+        pub fn swish_with_grad(x: f32, beta: f32) -> (f32, f32) {
+            let bx = beta * x;
+            let s = sigmoid(bx.max(-MAX_EXP_ARG));
+            (x * s, s * (1.0_f32 + bx * (1.0_f32 - s)))
+        }
+
+        /// Mish function and its derivative.
+        #[inline]
+        pub fn mish_with_grad(x: f32) -> (f32, f32) {
+            if x > 15.0_f32 {
+                (x, 1.0_f32)
+            } else {
+                let n = exp(x);
+                let m = n * (n + 2.0_f32);
+                let t = m / (m + 2.0_f32);
+                // d/dx tanh(softplus(x)) = (1 - t²) * sigmoid(x)
+                (x * t, t + x * (1.0_f32 - t * t) * n / (1.0_f32 + n))
+            }
+        }
+
+        /// Exponential linear unit and its derivative.
+        #[inline]
+        pub fn elu_with_grad(x: f32, alpha: f32) -> (f32, f32) {
+            if x > 0.0_f32 {
+                (x, 1.0_f32)
+            } else {
+                let e = alpha * exp(x);
+                (e - alpha, e)
+            }
+        }
+
+        /// Scaled exponential linear unit and its derivative.
+        #[inline]
+        pub fn selu_with_grad(x: f32) -> (f32, f32) {
+            let (y, dy) = elu_with_grad(x, SELU_ALPHA);
+            (SELU_LAMBDA * y, SELU_LAMBDA * dy)
+        }
+
+        /// Softsign function and its derivative.
+        #[inline]
+        pub fn softsign_with_grad(x: f32) -> (f32, f32) {
+            let inv = 1.0_f32 / (1.0_f32 + x.abs());
+            (x * inv, inv * inv)
+        }
+
+        slice_impl!(
+            /// Softplus function for a batch of inputs.
+            softplus_slice => softplus
+        );
+        slice_impl!(
+            /// Gaussian error linear unit for a batch of inputs, using the error function.
+            gelu_slice => gelu
+        );
+        slice_impl!(
+            /// Gaussian error linear unit for a batch of inputs, using the `tanh` formulation.
+            gelu_tanh_slice => gelu_tanh
+        );
+        slice_impl!(
+            /// Sigmoid linear unit for a batch of inputs.
+            silu_slice => silu
+        );
+        slice_impl!(
+            /// Swish function for a batch of inputs.
+            swish_slice => swish, beta
+        );
+        slice_impl!(
+            /// Mish function for a batch of inputs.
+            mish_slice => mish
+        );
+        slice_impl!(
+            /// Exponential linear unit for a batch of inputs.
+            elu_slice => elu, alpha
+        );
+        slice_impl!(
+            /// Scaled exponential linear unit for a batch of inputs.
+            selu_slice => selu
+        );
+        slice_impl!(
+            /// Softsign function for a batch of inputs.
+            softsign_slice => softsign
+        );
+    };
+}
+
+/// Activations built on [`crate::fast`] approximations.
+pub mod fast {
+    activation_impl!(fast);
+}
+
+/// Activations built on [`crate::faster`] approximations.
+pub mod faster {
+    activation_impl!(faster);
+}
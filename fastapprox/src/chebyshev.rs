@@ -0,0 +1,180 @@
+//! Chebyshev approximations of user functions, built at runtime.
+//!
+//! [`Chebyshev::fit`] interpolates a function at the Chebyshev nodes of an interval, which for
+//! smooth functions comes within a small factor of the best polynomial approximation of the same
+//! degree, and [`Chebyshev::eval`] evaluates the series with the Clenshaw recurrence. This turns
+//! an expensive scalar function, such as a calibration curve, into a few multiply-adds per call.
+//!
+//! # Examples
+//!
+//! ```
+//! use fastapprox::chebyshev::Chebyshev;
+//!
+//! let curve = |x: f32| 0.5 * (1.0 + (x as f64 / 2f64.sqrt()).tanh() as f32);
+//! let p = Chebyshev::<16>::fit(-4.0, 4.0, curve);
+//! assert!((p.eval(0.5) - curve(0.5)).abs() <= p.max_error() + 1e-6);
+//!
+//! let slope = p.derivative();
+//! let area = p.integral();
+//! assert!(slope.eval(0.0) > 0.0);
+//! assert!(area.eval(-4.0).abs() < 1e-5);
+//! ```
+//!
+//! [`Chebyshev::fit`]: crate::chebyshev::Chebyshev::fit
+//! [`Chebyshev::eval`]: crate::chebyshev::Chebyshev::eval
+
+use std::f64::consts::PI;
+
+/// Polynomial of degree `N` in the Chebyshev basis on the interval `[a, b]`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Chebyshev<const N: usize> {
+    a: f32,
+    b: f32,
+    /// Coefficient of `T_0`.
+    c0: f32,
+    /// Coefficients of `T_1` to `T_N`.
+    c: [f32; N],
+    error: f32,
+}
+
+impl<const N: usize> Chebyshev<N> {
+    /// Interpolates `f` at the `N + 1` Chebyshev nodes of `[a, b]`.
+    ///
+    /// `f` is called `2 N + 3` times: at the nodes, and at the extrema of `T_{N + 1}`, where the
+    /// error of the interpolant tends to peak, to estimate [`max_error`](Self::max_error).
+    ///
+    /// Panics unless `a < b`.
+    pub fn fit(a: f32, b: f32, mut f: impl FnMut(f32) -> f32) -> Self {
+        assert!(a < b, "empty interval [{}, {}]", a, b);
+        let m = N + 1;
+        let (mid, half) = (0.5 * (a as f64 + b as f64), 0.5 * (b as f64 - a as f64));
+        let theta: Vec<f64> = (0..m).map(|j| PI * (j as f64 + 0.5) / m as f64).collect();
+        let values: Vec<f64> = theta
+            .iter()
+            .map(|t| f((mid + half * t.cos()) as f32) as f64)
+            .collect();
+        let coefficient = |k: usize| {
+            let sum: f64 = theta
+                .iter()
+                .zip(&values)
+                .map(|(t, v)| v * (k as f64 * t).cos())
+                .sum();
+            sum * 2.0 / m as f64
+        };
+        let mut c = [0.0_f32; N];
+        for (k, ck) in c.iter_mut().enumerate() {
+            *ck = coefficient(k + 1) as f32;
+        }
+        let mut p = Chebyshev {
+            a,
+            b,
+            c0: (0.5 * coefficient(0)) as f32,
+            c,
+            error: 0.0,
+        };
+        p.error = (0..=m)
+            .map(|j| {
+                let x = ((mid + half * (PI * j as f64 / m as f64).cos()) as f32).clamp(a, b);
+                (p.eval(x) - f(x)).abs()
+            })
+            .fold(0.0_f32, f32::max);
+        p
+    }
+
+    /// Value of the series at `x`. Outside `[a, b]` the polynomial is extrapolated, which
+    /// quickly loses accuracy.
+    #[inline]
+    pub fn eval(&self, x: f32) -> f32 {
+        let t = (2.0_f32 * x - self.a - self.b) / (self.b - self.a);
+        let (mut b1, mut b2) = (0.0_f32, 0.0_f32);
+        for &ck in self.c.iter().rev() {
+            let b0 = ck + 2.0_f32 * t * b1 - b2;
+            b2 = b1;
+            b1 = b0;
+        }
+        self.c0 + t * b1 - b2
+    }
+
+    /// Estimated maximum absolute error on `[a, b]`.
+    ///
+    /// For a fitted series it is the largest error seen at the extrema of `T_{N + 1}`; for a
+    /// derivative or integral it is propagated from the series it was computed from.
+    pub fn max_error(&self) -> f32 {
+        self.error
+    }
+
+    /// The interval `[a, b]`.
+    pub fn domain(&self) -> (f32, f32) {
+        (self.a, self.b)
+    }
+
+    /// Coefficient of `T_k`, for `k` up to `N`, of the series in `t = (2 x - a - b) / (b - a)`.
+    pub fn coefficient(&self, k: usize) -> f32 {
+        if k == 0 {
+            self.c0
+        } else {
+            self.c[k - 1]
+        }
+    }
+
+    fn coefficients(&self) -> Vec<f64> {
+        let mut c = vec![self.c0 as f64];
+        c.extend(self.c.iter().map(|&ck| ck as f64));
+        c
+    }
+
+    fn with_coefficients(&self, c: &[f64], error: f64) -> Self {
+        let mut rest = [0.0_f32; N];
+        for (r, &ck) in rest.iter_mut().zip(&c[1..]) {
+            *r = ck as f32;
+        }
+        Chebyshev {
+            a: self.a,
+            b: self.b,
+            c0: c[0] as f32,
+            c: rest,
+            error: error as f32,
+        }
+    }
+
+    /// Derivative of the series, of degree `N - 1`.
+    ///
+    /// Its error is estimated from the one of the series by Markov's inequality, as if the error
+    /// were a polynomial of degree `N + 1`, so it grows like `N²`.
+    pub fn derivative(&self) -> Self {
+        let c = self.coefficients();
+        let scale = 2.0 / (self.b as f64 - self.a as f64);
+        // c'_{k - 1} = c'_{k + 1} + 2 k c_k, with c'_0 halved.
+        let mut d = vec![0.0; N + 2];
+        for k in (1..=N).rev() {
+            d[k - 1] = d[k + 1] + 2.0 * k as f64 * c[k];
+        }
+        d[0] *= 0.5;
+        let d: Vec<f64> = d[..=N].iter().map(|dk| dk * scale).collect();
+        let degree = (N + 1) as f64;
+        self.with_coefficients(&d, self.error as f64 * degree * degree * scale)
+    }
+
+    /// Integral of the series from `a`, which is zero at `a`.
+    ///
+    /// The exact integral has degree `N + 1`. Its leading term is dropped to keep the degree
+    /// and added to the error, along with the error of the series integrated over `[a, b]`.
+    pub fn integral(&self) -> Self {
+        let c = self.coefficients();
+        let half = 0.5 * (self.b as f64 - self.a as f64);
+        let at = |k: usize| c.get(k).copied().unwrap_or(0.0);
+        // C_k = (c_{k - 1} - c_{k + 1}) / (2 k), with c_0 counted twice in C_1.
+        let mut i = vec![0.0; N + 2];
+        for (k, ik) in i.iter_mut().enumerate().skip(1) {
+            let previous = if k == 1 { 2.0 * c[0] } else { at(k - 1) };
+            *ik = half * (previous - at(k + 1)) / (2.0 * k as f64);
+        }
+        let dropped = i.pop().unwrap_or(0.0);
+        // T_k(-1) = (-1)^k.
+        i[0] = -(1..=N)
+            .map(|k| if k % 2 == 0 { i[k] } else { -i[k] })
+            .sum::<f64>();
+        let error = self.error as f64 * 2.0 * half + 2.0 * dropped.abs();
+        self.with_coefficients(&i, error)
+    }
+}
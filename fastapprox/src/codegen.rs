@@ -0,0 +1,178 @@
+//! Rust source for fitted approximations, to check them in with no fitting cost at runtime.
+//!
+//! Every function here renders one `#[inline] pub fn name(x: f32) -> f32` with the constants
+//! written as `f32` literals, in the style of [`crate::fast`]. Literals are printed with the
+//! shortest representation that reads back to the same `f32`, so that the generated function
+//! returns exactly what the fitted object does.
+//!
+//! The functions only build strings, so they work from a build script as well, with
+//! `fastapprox` as a build dependency:
+//!
+//! ```no_run
+//! // build.rs
+//! use std::{env, fs, path::Path};
+//!
+//! use fastapprox::chebyshev::Chebyshev;
+//! use fastapprox::codegen;
+//!
+//! let curve = Chebyshev::<8>::fit(0.0, 1.0, |x| x.sqrt().sin());
+//! let source = codegen::chebyshev("curve", "Calibration curve on [0, 1].", &curve);
+//! let out = env::var("OUT_DIR").unwrap();
+//! fs::write(Path::new(&out).join("curve.rs"), source).unwrap();
+//!
+//! // lib.rs
+//! // include!(concat!(env!("OUT_DIR"), "/curve.rs"));
+//! ```
+
+use std::fmt::Write;
+
+use crate::chebyshev::Chebyshev;
+use crate::lut::{Edge, Interpolation, Lut};
+
+/// `x` as an `f32` literal that reads back to the same value.
+pub fn literal(x: f32) -> String {
+    if x.is_nan() {
+        "f32::NAN".to_string()
+    } else if x.is_infinite() {
+        if x > 0.0 {
+            "f32::INFINITY"
+        } else {
+            "f32::NEG_INFINITY"
+        }
+        .to_string()
+    } else {
+        format!("{:?}_f32", x)
+    }
+}
+
+/// `p(x)` evaluated by Horner's rule, with `coefficients` from the constant term up.
+fn horner(coefficients: &[f32], x: &str) -> String {
+    match coefficients.split_first() {
+        None => literal(0.0),
+        Some((c, [])) => literal(*c),
+        Some((c, rest)) => format!("{} + {} * ({})", literal(*c), x, horner(rest, x)),
+    }
+}
+
+/// Array of literals, a few per line, indented for the body of a function.
+fn array(values: &[f32]) -> String {
+    let mut s = String::from("[");
+    for (i, &v) in values.iter().enumerate() {
+        s.push_str(if i % 4 == 0 { "\n        " } else { " " });
+        s.push_str(&literal(v));
+        s.push(',');
+    }
+    s.push_str(if values.is_empty() { "]" } else { "\n    ]" });
+    s
+}
+
+/// Doc comment, `#[inline]` and signature, up to the opening brace.
+fn header(name: &str, doc: &str) -> String {
+    assert!(
+        name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'),
+        "invalid function name {:?}",
+        name
+    );
+    let mut s = String::new();
+    for line in doc.lines() {
+        if line.is_empty() {
+            s.push_str("///\n");
+        } else {
+            writeln!(s, "/// {}", line).unwrap();
+        }
+    }
+    writeln!(s, "#[inline]\npub fn {}(x: f32) -> f32 {{", name).unwrap();
+    s
+}
+
+/// Polynomial `c0 + c1 x + c2 x² + ...`, evaluated by Horner's rule.
+///
+/// Panics if `name` is not an identifier.
+pub fn polynomial(name: &str, doc: &str, coefficients: &[f32]) -> String {
+    format!(
+        "{}    {}\n}}\n",
+        header(name, doc),
+        horner(coefficients, "x")
+    )
+}
+
+/// Rational function `p(x) / q(x)`, with the coefficients of both polynomials from the
+/// constant term up.
+///
+/// Panics if `name` is not an identifier.
+pub fn rational(name: &str, doc: &str, numerator: &[f32], denominator: &[f32]) -> String {
+    format!(
+        "{}    ({}) / ({})\n}}\n",
+        header(name, doc),
+        horner(numerator, "x"),
+        horner(denominator, "x")
+    )
+}
+
+/// Chebyshev series, evaluated with the Clenshaw recurrence exactly like
+/// [`Chebyshev::eval`].
+///
+/// Panics if `name` is not an identifier.
+pub fn chebyshev<const N: usize>(name: &str, doc: &str, p: &Chebyshev<N>) -> String {
+    let (a, b) = p.domain();
+    let c: Vec<f32> = (1..=N).map(|k| p.coefficient(k)).collect();
+    let mut s = header(name, doc);
+    writeln!(s, "    const C: [f32; {}] = {};", N, array(&c)).unwrap();
+    let (a, b) = (literal(a), literal(b));
+    writeln!(
+        s,
+        "    let t = (2.0_f32 * x - {} - {}) / ({} - {});",
+        a, b, b, a
+    )
+    .unwrap();
+    s.push_str(
+        "    let (mut b1, mut b2) = (0.0_f32, 0.0_f32);\n    \
+         for &c in C.iter().rev() {\n        \
+         let b0 = c + 2.0_f32 * t * b1 - b2;\n        \
+         b2 = b1;\n        \
+         b1 = b0;\n    \
+         }\n",
+    );
+    writeln!(s, "    {} + t * b1 - b2\n}}", literal(p.coefficient(0))).unwrap();
+    s
+}
+
+/// Lookup table, as a constant [`Lut`] built with [`Lut::from_table`] at compile time.
+///
+/// The generated code refers to the [`crate::lut`] module as `::fastapprox::lut`.
+///
+/// Panics if `name` is not an identifier.
+pub fn lut<const N: usize>(name: &str, doc: &str, lut: &Lut<N>) -> String {
+    let (lo, hi) = lut.domain();
+    let interpolation = match lut.interpolation() {
+        Interpolation::Nearest => "Nearest",
+        Interpolation::Linear => "Linear",
+        Interpolation::Cubic => "Cubic",
+    };
+    let edge = match lut.edge() {
+        Edge::Clamp => "Clamp",
+        Edge::Extrapolate => "Extrapolate",
+    };
+    let mut s = header(name, doc);
+    writeln!(
+        s,
+        "    use ::fastapprox::lut::{{Edge, Interpolation, Lut}};"
+    )
+    .unwrap();
+    writeln!(
+        s,
+        "    const TABLE: Lut<{}> = Lut::from_table({}, {}, {})\n        \
+         .with_interpolation(Interpolation::{})\n        \
+         .with_edge(Edge::{});",
+        N,
+        literal(lo),
+        literal(hi),
+        array(lut.table()),
+        interpolation,
+        edge
+    )
+    .unwrap();
+    s.push_str("    TABLE.eval(x)\n}\n");
+    s
+}
@@ -0,0 +1,190 @@
+//! Functions with a `_slice` suffix evaluate the same density for a batch of observations,
+//! computing the normalizing constant only once.
+
+macro_rules! dist_impl {
+    ($tier:ident) => {
+        use crate::$tier::{cosfull, ln, ln_bessel_i0, ln_gamma, zeta};
+
+        const LN_PI: f32 = 1.1447299;
+        const LN_2PI: f32 = 1.837877;
+
+        /// Natural logarithm of the Beta function.
+        ///
+        /// Only works for positive values.
+        #[inline]
+        pub fn ln_beta(a: f32, b: f32) -> f32 {
+            ln_gamma(a) + ln_gamma(b) - ln_gamma(a + b)
+        }
+
+        /// Log-density of the Gamma distribution with given `shape` and `rate`.
+        #[inline]
+        pub fn gamma_ln_pdf(x: f32, shape: f32, rate: f32) -> f32 {
+            shape * ln(rate) - ln_gamma(shape) + (shape - 1.0_f32) * ln(x) - rate * x
+        }
+
+        /// Log-density of the Gamma distribution for a batch of observations.
+        #[inline]
+        pub fn gamma_ln_pdf_slice(xs: &[f32], shape: f32, rate: f32, out: &mut [f32]) {
+            assert_eq!(xs.len(), out.len());
+            let norm = shape * ln(rate) - ln_gamma(shape);
+            for (y, &x) in out.iter_mut().zip(xs) {
+                *y = norm + (shape - 1.0_f32) * ln(x) - rate * x;
+            }
+        }
+
+        /// Log-density of the Beta distribution with shape parameters `a` and `b`.
+        ///
+        /// Only works for `x` in (0, 1).
+        #[inline]
+        pub fn beta_ln_pdf(x: f32, a: f32, b: f32) -> f32 {
+            (a - 1.0_f32) * ln(x) + (b - 1.0_f32) * ln(1.0_f32 - x) - ln_beta(a, b)
+        }
+
+        /// Log-density of the Beta distribution for a batch of observations.
+        #[inline]
+        pub fn beta_ln_pdf_slice(xs: &[f32], a: f32, b: f32, out: &mut [f32]) {
+            assert_eq!(xs.len(), out.len());
+            let norm = ln_beta(a, b);
+            for (y, &x) in out.iter_mut().zip(xs) {
+                *y = (a - 1.0_f32) * ln(x) + (b - 1.0_f32) * ln(1.0_f32 - x) - norm;
+            }
+        }
+
+        /// Log-density of the Dirichlet distribution with concentration `alpha`.
+        ///
+        /// `x` must lie on the probability simplex and have the same length as `alpha`.
+        #[inline]
+        pub fn dirichlet_ln_pdf(x: &[f32], alpha: &[f32]) -> f32 {
+            assert_eq!(x.len(), alpha.len());
+            let mut sum = 0.0_f32;
+            let mut acc = 0.0_f32;
+            for (&xi, &ai) in x.iter().zip(alpha) {
+                sum += ai;
+                acc += (ai - 1.0_f32) * ln(xi) - ln_gamma(ai);
+            }
+            acc + ln_gamma(sum)
+        }
+
+        /// Log-probability of the Poisson distribution with given `lambda`.
+        #[inline]
+        pub fn poisson_ln_pmf(k: u32, lambda: f32) -> f32 {
+            let k = k as f32;
+            k * ln(lambda) - lambda - ln_gamma(k + 1.0_f32)
+        }
+
+        /// Log-probability of the Poisson distribution for a batch of observations.
+        #[inline]
+        pub fn poisson_ln_pmf_slice(ks: &[u32], lambda: f32, out: &mut [f32]) {
+            assert_eq!(ks.len(), out.len());
+            let lnlambda = ln(lambda);
+            for (y, &k) in out.iter_mut().zip(ks) {
+                let k = k as f32;
+                *y = k * lnlambda - lambda - ln_gamma(k + 1.0_f32);
+            }
+        }
+
+        /// Log-probability of the negative binomial distribution.
+        ///
+        /// `k` is the number of failures before `r` successes with success probability `p`.
+        #[inline]
+        pub fn neg_binomial_ln_pmf(k: u32, r: f32, p: f32) -> f32 {
+            let k = k as f32;
+            ln_gamma(k + r) - ln_gamma(k + 1.0_f32) - ln_gamma(r) + r * ln(p) + k * ln(1.0_f32 - p)
+        }
+
+        /// Log-probability of the negative binomial distribution for a batch of observations.
+        #[inline]
+        pub fn neg_binomial_ln_pmf_slice(ks: &[u32], r: f32, p: f32, out: &mut [f32]) {
+            assert_eq!(ks.len(), out.len());
+            let norm = r * ln(p) - ln_gamma(r);
+            let lnq = ln(1.0_f32 - p);
+            for (y, &k) in out.iter_mut().zip(ks) {
+                let k = k as f32;
+                *y = norm + ln_gamma(k + r) - ln_gamma(k + 1.0_f32) + k * lnq;
+            }
+        }
+
+        /// Log-density of the Student's t-distribution.
+        ///
+        /// `freedom` is the number of degrees of freedom.
+        #[inline]
+        pub fn student_t_ln_pdf(x: f32, location: f32, scale: f32, freedom: f32) -> f32 {
+            let z = (x - location) / scale;
+            let halfnp1 = 0.5_f32 * (freedom + 1.0_f32);
+            ln_gamma(halfnp1)
+                - ln_gamma(0.5_f32 * freedom)
+                - 0.5_f32 * (ln(freedom) + LN_PI)
+                - ln(scale)
+                - halfnp1 * ln(1.0_f32 + z * z / freedom)
+        }
+
+        /// Log-density of the Student's t-distribution for a batch of observations.
+        #[inline]
+        pub fn student_t_ln_pdf_slice(
+            xs: &[f32],
+            location: f32,
+            scale: f32,
+            freedom: f32,
+            out: &mut [f32],
+        ) {
+            assert_eq!(xs.len(), out.len());
+            let halfnp1 = 0.5_f32 * (freedom + 1.0_f32);
+            let norm = ln_gamma(halfnp1)
+                - ln_gamma(0.5_f32 * freedom)
+                - 0.5_f32 * (ln(freedom) + LN_PI)
+                - ln(scale);
+            let invscale = 1.0_f32 / scale;
+            let invfreedom = 1.0_f32 / freedom;
+            for (y, &x) in out.iter_mut().zip(xs) {
+                let z = (x - location) * invscale;
+                *y = norm - halfnp1 * ln(1.0_f32 + z * z * invfreedom);
+            }
+        }
+
+        /// Log-density of the von Mises distribution with mean `mu` and concentration `kappa`.
+        ///
+        /// Angles are in radians, and `x - mu` must lie in \[-2π, 2π\].
+        #[inline]
+        pub fn von_mises_ln_pdf(x: f32, mu: f32, kappa: f32) -> f32 {
+            kappa * cosfull(x - mu) - LN_2PI - ln_bessel_i0(kappa)
+        }
+
+        /// Log-density of the von Mises distribution for a batch of observations.
+        #[inline]
+        pub fn von_mises_ln_pdf_slice(xs: &[f32], mu: f32, kappa: f32, out: &mut [f32]) {
+            assert_eq!(xs.len(), out.len());
+            let norm = LN_2PI + ln_bessel_i0(kappa);
+            for (y, &x) in out.iter_mut().zip(xs) {
+                *y = kappa * cosfull(x - mu) - norm;
+            }
+        }
+
+        /// Log-probability of the Zipf (zeta) distribution with exponent `s` over `k ≥ 1`.
+        ///
+        /// Only works for `s > 1`.
+        #[inline]
+        pub fn zipf_ln_pmf(k: u32, s: f32) -> f32 {
+            -s * ln(k as f32) - ln(zeta(s))
+        }
+
+        /// Log-probability of the Zipf (zeta) distribution for a batch of observations.
+        #[inline]
+        pub fn zipf_ln_pmf_slice(ks: &[u32], s: f32, out: &mut [f32]) {
+            assert_eq!(ks.len(), out.len());
+            let norm = ln(zeta(s));
+            for (y, &k) in out.iter_mut().zip(ks) {
+                *y = -s * ln(k as f32) - norm;
+            }
+        }
+    };
+}
+
+/// Log-densities built on [`crate::fast`] approximations.
+pub mod fast {
+    dist_impl!(fast);
+}
+
+/// Log-densities built on [`crate::faster`] approximations.
+pub mod faster {
+    dist_impl!(faster);
+}
@@ -0,0 +1,138 @@
+//! Distributions are given as slices of probabilities, and terms of the form `0 · ln 0` count as
+//! zero.
+//! Functions with a `_clamped` suffix clamp the probabilities they take the logarithm of to
+//! `[eps, 1 - eps]`, so that they stay finite when a predicted probability is zero.
+
+macro_rules! entropy_impl {
+    ($tier:ident) => {
+        use crate::$tier::ln;
+
+        /// `x · ln y`, which is zero for `x = 0` and negative infinity for `y = 0 < x`.
+        ///
+        /// `ln 1` is special-cased, so that certain outcomes contribute exactly zero.
+        #[inline]
+        fn xlny(x: f32, y: f32) -> f32 {
+            if x == 0.0_f32 || y == 1.0_f32 {
+                0.0_f32
+            } else if y == 0.0_f32 {
+                f32::NEG_INFINITY
+            } else {
+                x * ln(y)
+            }
+        }
+
+        /// Shannon entropy `-Σ p ln p` in nats.
+        #[inline]
+        pub fn entropy(p: &[f32]) -> f32 {
+            -p.iter().map(|&pi| xlny(pi, pi)).sum::<f32>()
+        }
+
+        /// Cross-entropy `-Σ p ln q` in nats.
+        ///
+        /// Infinite if `q` is zero where `p` is not.
+        #[inline]
+        pub fn cross_entropy(p: &[f32], q: &[f32]) -> f32 {
+            assert_eq!(p.len(), q.len());
+            -p.iter().zip(q).map(|(&pi, &qi)| xlny(pi, qi)).sum::<f32>()
+        }
+
+        /// Cross-entropy with `q` clamped to `[eps, 1 - eps]`.
+        #[inline]
+        pub fn cross_entropy_clamped(p: &[f32], q: &[f32], eps: f32) -> f32 {
+            assert_eq!(p.len(), q.len());
+            -p.iter()
+                .zip(q)
+                .map(|(&pi, &qi)| xlny(pi, qi.clamp(eps, 1.0_f32 - eps)))
+                .sum::<f32>()
+        }
+
+        /// Kullback–Leibler divergence `Σ p ln(p / q)` in nats.
+        ///
+        /// Infinite if `q` is zero where `p` is not.
+        #[inline]
+        pub fn kl_divergence(p: &[f32], q: &[f32]) -> f32 {
+            assert_eq!(p.len(), q.len());
+            p.iter()
+                .zip(q)
+                .map(|(&pi, &qi)| xlny(pi, pi) - xlny(pi, qi))
+                .sum()
+        }
+
+        /// Kullback–Leibler divergence with `q` clamped to `[eps, 1 - eps]`.
+        #[inline]
+        pub fn kl_divergence_clamped(p: &[f32], q: &[f32], eps: f32) -> f32 {
+            assert_eq!(p.len(), q.len());
+            p.iter()
+                .zip(q)
+                .map(|(&pi, &qi)| xlny(pi, pi) - xlny(pi, qi.clamp(eps, 1.0_f32 - eps)))
+                .sum()
+        }
+
+        /// Jensen–Shannon divergence in nats, the mean Kullback–Leibler divergence of `p` and
+        /// `q` from their mixture.
+        ///
+        /// Always finite, and clamped to \[0, ln 2\], the range of the exact divergence, which
+        /// the error of the logarithm could otherwise leave.
+        #[inline]
+        pub fn js_divergence(p: &[f32], q: &[f32]) -> f32 {
+            assert_eq!(p.len(), q.len());
+            let sum: f32 = p
+                .iter()
+                .zip(q)
+                .map(|(&pi, &qi)| {
+                    let m = 0.5_f32 * (pi + qi);
+                    xlny(pi, pi) + xlny(qi, qi) - xlny(pi + qi, m)
+                })
+                .sum();
+            (0.5_f32 * sum).clamp(0.0_f32, std::f32::consts::LN_2)
+        }
+
+        #[inline]
+        fn binary_term(p: f32, label: f32) -> f32 {
+            -xlny(label, p) - xlny(1.0_f32 - label, 1.0_f32 - p)
+        }
+
+        /// Mean binary cross-entropy of predicted probabilities `p` against `labels` in
+        /// \[0, 1\].
+        ///
+        /// Infinite if a prediction is certain and wrong, zero for empty slices.
+        #[inline]
+        pub fn binary_cross_entropy(p: &[f32], labels: &[f32]) -> f32 {
+            assert_eq!(p.len(), labels.len());
+            if p.is_empty() {
+                return 0.0_f32;
+            }
+            let sum: f32 = p
+                .iter()
+                .zip(labels)
+                .map(|(&pi, &y)| binary_term(pi, y))
+                .sum();
+            sum / p.len() as f32
+        }
+
+        /// Mean binary cross-entropy with `p` clamped to `[eps, 1 - eps]`.
+        #[inline]
+        pub fn binary_cross_entropy_clamped(p: &[f32], labels: &[f32], eps: f32) -> f32 {
+            assert_eq!(p.len(), labels.len());
+            if p.is_empty() {
+                return 0.0_f32;
+            }
+            let sum: f32 = p
+                .iter()
+                .zip(labels)
+                .map(|(&pi, &y)| binary_term(pi.clamp(eps, 1.0_f32 - eps), y))
+                .sum();
+            sum / p.len() as f32
+        }
+    };
+}
+
+/// Entropies and divergences built on [`crate::fast`] approximations.
+pub mod fast {
+    entropy_impl!(fast);
+}
+
+/// Entropies and divergences built on [`crate::faster`] approximations.
+pub mod faster {
+    entropy_impl!(faster);
+}
@@ -3,6 +3,7 @@ use crate::faster;
 
 /// Base 2 logarithm.
 #[inline]
+#[allow(clippy::excessive_precision)]
 pub fn log2(x: f32) -> f32 {
     let vx = to_bits(x);
     let mx = from_bits((vx & 0x007FFFFF_u32) | 0x3f000000);
@@ -13,12 +14,14 @@ pub fn log2(x: f32) -> f32 {
 
 /// Natural logarithm.
 #[inline]
+#[allow(clippy::approx_constant, clippy::excessive_precision)]
 pub fn ln(x: f32) -> f32 {
     0.69314718_f32 * log2(x)
 }
 
 /// Raises 2 to a floating point power.
 #[inline]
+#[allow(clippy::excessive_precision)]
 pub fn pow2(p: f32) -> f32 {
     let offset = if p < 0.0 { 1.0_f32 } else { 0.0_f32 };
     let clipp = if p < -126.0 { -126.0_f32 } else { p };
@@ -38,6 +41,7 @@ pub fn pow(x: f32, p: f32) -> f32 {
 
 /// Exponential function.
 #[inline]
+#[allow(clippy::approx_constant, clippy::excessive_precision)]
 pub fn exp(p: f32) -> f32 {
     pow2(1.442695040_f32 * p)
 }
@@ -48,10 +52,23 @@ pub fn sigmoid(x: f32) -> f32 {
     1.0_f32 / (1.0_f32 + exp(-x))
 }
 
+/// Inverse square root.
+///
+/// Only works for positive values.
+/// The bit-level initial guess is refined with two Newton iterations.
+#[inline]
+pub fn rsqrt(x: f32) -> f32 {
+    let halfx = 0.5_f32 * x;
+    let mut y = from_bits(0x5f375a86_u32 - (to_bits(x) >> 1));
+    y *= 1.5_f32 - halfx * y * y;
+    y * (1.5_f32 - halfx * y * y)
+}
+
 /// Natural logarithm of the Gamma function.
 ///
 /// Only works for positive values.
 #[inline]
+#[allow(clippy::excessive_precision)]
 pub fn ln_gamma(x: f32) -> f32 {
     let logterm = ln(x * (1.0_f32 + x) * (2.0_f32 + x));
     let xp3 = 3.0_f32 + x;
@@ -74,6 +91,7 @@ pub fn digamma(x: f32) -> f32 {
 
 /// Complementary error function.
 #[inline]
+#[allow(clippy::excessive_precision)]
 pub fn erfc(x: f32) -> f32 {
     const K: f32 = 3.3509633149424609;
     const A: f32 = 0.07219054755431126;
@@ -97,6 +115,7 @@ pub fn erf(x: f32) -> f32 {
 
 /// Inverse error function.
 #[inline]
+#[allow(clippy::excessive_precision)]
 pub fn erf_inv(x: f32) -> f32 {
     const INVK: f32 = 0.30004578719350504;
     const A: f32 = 0.020287853348211326;
@@ -129,6 +148,7 @@ pub fn tanh(p: f32) -> f32 {
 
 /// Lambert W function.
 #[inline]
+#[allow(clippy::excessive_precision)]
 pub fn lambertw(x: f32) -> f32 {
     const THRESHOLD: f32 = 2.26445;
 
@@ -162,6 +182,7 @@ pub fn lambertw(x: f32) -> f32 {
 
 /// Exponent of Lambert W function.
 #[inline]
+#[allow(clippy::excessive_precision)]
 pub fn lambertwexpx(x: f32) -> f32 {
     const K: f32 = 1.1765631309;
     const A: f32 = 0.94537622168;
@@ -181,6 +202,7 @@ pub fn lambertwexpx(x: f32) -> f32 {
 
 /// Sine of a number in \[-π, π\], in radians.
 #[inline]
+#[allow(clippy::excessive_precision)]
 pub fn sin(x: f32) -> f32 {
     const FOUROVERPI: f32 = 1.2732395447351627;
     const FOUROVERPISQ: f32 = 0.40528473456935109;
@@ -208,11 +230,12 @@ pub fn sin(x: f32) -> f32 {
 ///
 /// The range reduction technique used here will be hopelessly inaccurate for |x| >> 1000.
 #[inline]
+#[allow(clippy::approx_constant, clippy::excessive_precision)]
 pub fn sinfull(x: f32) -> f32 {
     const TWOPI: f32 = 6.2831853071795865;
     const INVTWOPI: f32 = 0.15915494309189534;
 
-    let k: u32 = (x * INVTWOPI) as u32;
+    let k: i32 = (x * INVTWOPI) as i32;
     let half = if x < 0_f32 { -0.5_f32 } else { 0.5_f32 };
     sin((half + (k as f32)) * TWOPI - x)
 }
@@ -226,6 +249,7 @@ pub fn sinfull(x: f32) -> f32 {
 /// assert_eq!(fastapprox::fast::cos(1.0), 0.5402951);
 /// ```
 #[inline]
+#[allow(clippy::approx_constant, clippy::excessive_precision)]
 pub fn cos(x: f32) -> f32 {
     const HALFPI: f32 = 1.5707963267948966;
     const HALFPIMINUSTWOPI: f32 = -4.7123889803846899;
@@ -244,6 +268,7 @@ pub fn cos(x: f32) -> f32 {
 /// assert_eq!(fastapprox::fast::cosfull(10.0), -0.83907986);
 /// ```
 #[inline]
+#[allow(clippy::approx_constant, clippy::excessive_precision)]
 pub fn cosfull(x: f32) -> f32 {
     const HALFPI: f32 = 1.5707963267948966;
     sinfull(x + HALFPI)
@@ -251,6 +276,7 @@ pub fn cosfull(x: f32) -> f32 {
 
 /// Tangent of a number in \[-π/2, π/2\], in radians.
 #[inline]
+#[allow(clippy::approx_constant, clippy::excessive_precision)]
 pub fn tan(x: f32) -> f32 {
     const HALFPI: f32 = 1.5707963267948966;
     sin(x) / sin(x + HALFPI)
@@ -260,13 +286,276 @@ pub fn tan(x: f32) -> f32 {
 ///
 /// The range reduction technique used here will be hopelessly inaccurate for |x| >> 1000.
 #[inline]
+#[allow(clippy::approx_constant, clippy::excessive_precision)]
 pub fn tanfull(x: f32) -> f32 {
     const TWOPI: f32 = 6.2831853071795865;
     const INVTWOPI: f32 = 0.15915494309189534;
 
-    let k: u32 = (x * INVTWOPI) as u32;
+    let k: i32 = (x * INVTWOPI) as i32;
     let half = if x < 0_f32 { -0.5_f32 } else { 0.5_f32 };
     let xnew = x - (half + k as f32) * TWOPI;
 
     sin(xnew) / cos(xnew)
 }
+
+/// Scaled complementary error function `exp(x²) * erfc(x)`.
+///
+/// Unlike [`erfc`], stays accurate for large positive values.
+/// The rational form of [`erfc`] cannot be reused, as it decays like `2^(-K x)` instead of
+/// `exp(-x²)`, so that its product with `exp(x²)` blows up. This uses the form of `erfcc` from
+/// Numerical Recipes instead, `t exp(P(t))` with `t = 1 / (1 + |x| / 2)`, which has `exp(-x²)`
+/// factored out.
+#[inline]
+pub fn erfcx(x: f32) -> f32 {
+    let z = x.abs();
+    let t = 1.0_f32 / (1.0_f32 + 0.5_f32 * z);
+    let r = t * exp(erfcx_ln_poly(t));
+
+    if x < 0.0_f32 {
+        2.0_f32 * exp(x * x) - r
+    } else {
+        r
+    }
+}
+
+/// Natural logarithm of the complementary error function.
+///
+/// Unlike `ln(erfc(x))`, stays finite for large positive values.
+#[inline]
+pub fn ln_erfc(x: f32) -> f32 {
+    let z = x.abs();
+    let t = 1.0_f32 / (1.0_f32 + 0.5_f32 * z);
+    let p = erfcx_ln_poly(t) - z * z;
+
+    if x < 0.0_f32 {
+        ln(2.0_f32 - t * exp(p))
+    } else {
+        ln(t) + p
+    }
+}
+
+/// `ln(erfcx(z) / t)`, with the coefficients of `erfcc` in Numerical Recipes in C (2nd ed.,
+/// section 6.2), a Chebyshev fit with a relative error below 1.2e-7.
+#[inline]
+#[allow(clippy::excessive_precision)]
+fn erfcx_ln_poly(t: f32) -> f32 {
+    -1.26551223_f32
+        + t * (1.00002368_f32
+            + t * (0.37409196_f32
+                + t * (0.09678418_f32
+                    + t * (-0.18628806_f32
+                        + t * (0.27886807_f32
+                            + t * (-1.13520398_f32
+                                + t * (1.48851587_f32
+                                    + t * (-0.82215223_f32 + t * 0.17087277_f32))))))))
+}
+
+/// Riemann zeta function.
+///
+/// Only works for `s > 1`.
+#[inline]
+pub fn zeta(s: f32) -> f32 {
+    1.0_f32 + pow2(-s) + zeta_tail(s, 3.0_f32)
+}
+
+/// Hurwitz zeta function `ζ(s, q) = Σ (q + k)^(-s)` over `k ≥ 0`.
+///
+/// Only works for `s > 1` and positive `q`.
+#[inline]
+pub fn hurwitz_zeta(s: f32, q: f32) -> f32 {
+    pow(q, -s) + pow(1.0_f32 + q, -s) + zeta_tail(s, 2.0_f32 + q)
+}
+
+/// Euler–Maclaurin estimate of `Σ (a + k)^(-s)` over `k ≥ 0`.
+#[inline]
+fn zeta_tail(s: f32, a: f32) -> f32 {
+    let inva = 1.0_f32 / a;
+    let invasq = inva * inva;
+
+    pow(a, -s)
+        * (a / (s - 1.0_f32)
+            + 0.5_f32
+            + s * inva
+                * (0.083333333_f32 - (s + 1.0_f32) * (s + 2.0_f32) * invasq * 0.0013888889_f32))
+}
+
+/// Exponential integral E1.
+///
+/// Only works for positive values, with relative error below 4e-4.
+/// Uses Abramowitz and Stegun 5.1.53 below one and 5.1.54 above.
+#[inline]
+#[allow(clippy::excessive_precision)]
+pub fn exp_int_e1(x: f32) -> f32 {
+    if x <= 1.0_f32 {
+        -ln(x) - 0.57721566_f32
+            + x * (0.99999193_f32
+                + x * (-0.24991055_f32
+                    + x * (0.05519968_f32 + x * (-0.00976004_f32 + x * 0.00107857_f32))))
+    } else {
+        let num = 0.2677737343_f32
+            + x * (8.6347608925_f32 + x * (18.0590169730_f32 + x * (8.5733287401_f32 + x)));
+        let den = 3.9584969228_f32
+            + x * (21.0996530827_f32 + x * (25.6329561486_f32 + x * (9.5733223454_f32 + x)));
+        exp(-x) * num / (x * den)
+    }
+}
+
+/// Exponential integral Ei.
+///
+/// Works for non-zero values, with relative error below 2e-4 for positive x and below 4e-4
+/// for negative x, where it is -E1(-x), subnormals included. Around the root at x ≈ 0.3725, a polynomial in
+/// x - x0, with x0 split in two floats, keeps the error relative, below 1e-6. Below 6 the
+/// series γ + ln x + x P(x) and above 6 the asymptotic form eˣ/x R(1/x) are minimax fits, which
+/// `fastapprox-fit fast::exp_int_ei` re-derives with the root and large forms.
+#[inline]
+pub fn exp_int_ei(x: f32) -> f32 {
+    const EULER: f32 = 0.5772157;
+    const ROOT: f32 = 0.37250742;
+    const ROOT_LO: f32 = -1.23810935e-8;
+    const LN_2_24: f32 = 16.635532;
+
+    if x.abs() < f32::MIN_POSITIVE {
+        // The bit tricks of ln assume a normal float, so subnormals are scaled by 2^24, and the
+        // terms in x are below the resolution of γ + ln |x|.
+        EULER + ln(x.abs() * 16777216.0_f32) - LN_2_24
+    } else if x < 0.0_f32 {
+        -exp_int_e1(-x)
+    } else if (0.25_f32..=0.5_f32).contains(&x) {
+        let d = (x - ROOT) - ROOT_LO;
+        d * (3.8962157_f32
+            + d * (-3.2817721_f32
+                + d * (6.522805_f32
+                    + d * (-12.886721_f32
+                        + d * (27.708103_f32 + d * (-72.67686_f32 + d * 166.16518_f32))))))
+    } else if x < 6.0_f32 {
+        EULER
+            + ln(x)
+            + x * (0.9991307_f32
+                + x * (0.255736_f32
+                    + x * (0.04176851_f32
+                        + x * (0.025929598_f32
+                            + x * (-0.0074612694_f32
+                                + x * (0.0031546766_f32
+                                    + x * (-0.0004650312_f32 + x * 3.9772465e-05_f32)))))))
+    } else {
+        let u = 1.0_f32 / x;
+        let num = 0.99988014_f32 + u * (-11.07751_f32 + u * (44.80629_f32 - u * 20.112457_f32));
+        let den = 1.0_f32 + u * (-12.090321_f32 + u * (55.28323_f32 - u * 60.91256_f32));
+        exp(x) * u * num / den
+    }
+}
+
+/// Logarithmic integral li.
+///
+/// Works for positive values other than one, with relative error below 3e-4 from two upwards.
+#[inline]
+pub fn log_int(x: f32) -> f32 {
+    exp_int_ei(ln(x))
+}
+
+/// Modified Bessel function of the first kind of order zero.
+///
+/// Polynomial fits from Abramowitz and Stegun 9.8.1 and 9.8.2.
+#[inline]
+pub fn bessel_i0(x: f32) -> f32 {
+    let ax = x.abs();
+    if ax < 3.75_f32 {
+        i0_small(ax)
+    } else {
+        exp(ax) * i0_large(3.75_f32 / ax) / ax.sqrt()
+    }
+}
+
+/// Modified Bessel function of the first kind of order one.
+///
+/// Polynomial fits from Abramowitz and Stegun 9.8.3 and 9.8.4.
+#[inline]
+pub fn bessel_i1(x: f32) -> f32 {
+    let ax = x.abs();
+    let i1 = if ax < 3.75_f32 {
+        ax * i1_small(ax)
+    } else {
+        exp(ax) * i1_large(3.75_f32 / ax) / ax.sqrt()
+    };
+    if x < 0.0_f32 {
+        -i1
+    } else {
+        i1
+    }
+}
+
+/// Natural logarithm of the modified Bessel function of the first kind of order zero.
+///
+/// Does not overflow for large arguments.
+#[inline]
+pub fn ln_bessel_i0(x: f32) -> f32 {
+    let ax = x.abs();
+    if ax < 3.75_f32 {
+        ln(i0_small(ax))
+    } else {
+        ax - 0.5_f32 * ln(ax) + ln(i0_large(3.75_f32 / ax))
+    }
+}
+
+/// Ratio of modified Bessel functions of the first kind I1(x) / I0(x).
+///
+/// This is the mean resultant length of the von Mises distribution with concentration `x`.
+#[inline]
+pub fn bessel_i1_over_i0(x: f32) -> f32 {
+    let ax = x.abs();
+    if ax < 3.75_f32 {
+        x * i1_small(ax) / i0_small(ax)
+    } else {
+        let t = 3.75_f32 / ax;
+        let r = i1_large(t) / i0_large(t);
+        if x < 0.0_f32 {
+            -r
+        } else {
+            r
+        }
+    }
+}
+
+#[inline]
+fn i0_small(ax: f32) -> f32 {
+    let t = ax * ax * (1.0_f32 / 14.0625_f32);
+    1.0_f32
+        + t * (3.5156229_f32
+            + t * (3.0899424_f32
+                + t * (1.2067492_f32
+                    + t * (0.2659732_f32 + t * (0.0360768_f32 + t * 0.0045813_f32)))))
+}
+
+#[inline]
+#[allow(clippy::excessive_precision)]
+fn i0_large(t: f32) -> f32 {
+    0.39894228_f32
+        + t * (0.01328592_f32
+            + t * (0.00225319_f32
+                + t * (-0.00157565_f32
+                    + t * (0.00916281_f32
+                        + t * (-0.02057706_f32
+                            + t * (0.02635537_f32 + t * (-0.01647633_f32 + t * 0.00392377_f32)))))))
+}
+
+#[inline]
+fn i1_small(ax: f32) -> f32 {
+    let t = ax * ax * (1.0_f32 / 14.0625_f32);
+    0.5_f32
+        + t * (0.87890594_f32
+            + t * (0.51498869_f32
+                + t * (0.15084934_f32
+                    + t * (0.02658733_f32 + t * (0.00301532_f32 + t * 0.00032411_f32)))))
+}
+
+#[inline]
+#[allow(clippy::excessive_precision)]
+fn i1_large(t: f32) -> f32 {
+    0.39894228_f32
+        + t * (-0.03988024_f32
+            + t * (-0.00362018_f32
+                + t * (0.00163801_f32
+                    + t * (-0.01031555_f32
+                        + t * (0.02282967_f32
+                            + t * (-0.02895312_f32 + t * (0.01787654_f32 - t * 0.00420059_f32)))))))
+}
@@ -2,6 +2,7 @@ use crate::bits::*;
 
 /// Base 2 logarithm.
 #[inline]
+#[allow(clippy::excessive_precision)]
 pub fn log2(x: f32) -> f32 {
     let mut y = to_bits(x) as f32;
     y *= 1.1920928955078125e-7_f32;
@@ -10,6 +11,7 @@ pub fn log2(x: f32) -> f32 {
 
 /// Natural logarithm.
 #[inline]
+#[allow(clippy::excessive_precision)]
 pub fn ln(x: f32) -> f32 {
     let mut y = to_bits(x) as f32;
     y *= 8.2629582881927490e-8_f32;
@@ -18,6 +20,7 @@ pub fn ln(x: f32) -> f32 {
 
 /// Raises 2 to a floating point power.
 #[inline]
+#[allow(clippy::excessive_precision)]
 pub fn pow2(p: f32) -> f32 {
     let clipp = if p < -126.0 { -126.0_f32 } else { p };
     let v = ((1 << 23) as f32 * (clipp + 126.94269504_f32)) as u32;
@@ -32,6 +35,7 @@ pub fn pow(x: f32, p: f32) -> f32 {
 
 /// Exponential function.
 #[inline]
+#[allow(clippy::approx_constant, clippy::excessive_precision)]
 pub fn exp(p: f32) -> f32 {
     pow2(1.442695040_f32 * p)
 }
@@ -42,10 +46,21 @@ pub fn sigmoid(x: f32) -> f32 {
     1.0_f32 / (1.0_f32 + exp(-x))
 }
 
+/// Inverse square root.
+///
+/// Only works for positive values.
+/// The bit-level initial guess is refined with one Newton iteration.
+#[inline]
+pub fn rsqrt(x: f32) -> f32 {
+    let y = from_bits(0x5f375a86_u32 - (to_bits(x) >> 1));
+    y * (1.5_f32 - 0.5_f32 * x * y * y)
+}
+
 /// Natural logarithm of the Gamma function.
 ///
 /// Only works for positive values.
 #[inline]
+#[allow(clippy::excessive_precision)]
 pub fn ln_gamma(x: f32) -> f32 {
     -0.0810614667_f32 - x - ln(x) + (0.5_f32 + x) * ln(1.0_f32 + x)
 }
@@ -61,6 +76,7 @@ pub fn digamma(x: f32) -> f32 {
 
 /// Complementary error function.
 #[inline]
+#[allow(clippy::excessive_precision)]
 pub fn erfc(x: f32) -> f32 {
     const K: f32 = 3.3509633149424609;
 
@@ -75,6 +91,7 @@ pub fn erf(x: f32) -> f32 {
 
 /// Inverse error function.
 #[inline]
+#[allow(clippy::excessive_precision)]
 pub fn erf_inv(x: f32) -> f32 {
     const INVK: f32 = 0.30004578719350504;
 
@@ -101,6 +118,7 @@ pub fn tanh(p: f32) -> f32 {
 
 /// Lambert W function.
 #[inline]
+#[allow(clippy::excessive_precision)]
 pub fn lambertw(x: f32) -> f32 {
     const THRESHOLD: f32 = 2.26445;
 
@@ -131,6 +149,7 @@ pub fn lambertw(x: f32) -> f32 {
 
 /// Exponent of Lambert W function.
 #[inline]
+#[allow(clippy::excessive_precision)]
 pub fn lambertwexpx(x: f32) -> f32 {
     const K: f32 = 1.1765631309;
     const A: f32 = 0.94537622168;
@@ -149,6 +168,7 @@ pub fn lambertwexpx(x: f32) -> f32 {
 
 /// Sine of a number in \[-π, π\], in radians.
 #[inline]
+#[allow(clippy::excessive_precision)]
 pub fn sin(x: f32) -> f32 {
     const FOUROVERPI: f32 = 1.2732395447351627;
     const FOUROVERPISQ: f32 = 0.40528473456935109;
@@ -171,6 +191,7 @@ pub fn sin(x: f32) -> f32 {
 ///
 /// The range reduction technique used here will be hopelessly inaccurate for |x| >> 1000.
 #[inline]
+#[allow(clippy::approx_constant, clippy::excessive_precision)]
 pub fn sinfull(x: f32) -> f32 {
     const TWOPI: f32 = 6.2831853071795865;
     const INVTWOPI: f32 = 0.15915494309189534;
@@ -189,6 +210,7 @@ pub fn sinfull(x: f32) -> f32 {
 /// assert_eq!(fastapprox::faster::cos(1.0), 0.5357177);
 /// ```
 #[inline]
+#[allow(clippy::approx_constant, clippy::excessive_precision)]
 pub fn cos(x: f32) -> f32 {
     const TWOOVERPI: f32 = 0.63661977236758134;
     const P: f32 = 0.54641335845679634;
@@ -211,6 +233,7 @@ pub fn cos(x: f32) -> f32 {
 /// assert_eq!(fastapprox::faster::cosfull(10.0), -0.8394889);
 /// ```
 #[inline]
+#[allow(clippy::approx_constant, clippy::excessive_precision)]
 pub fn cosfull(x: f32) -> f32 {
     const HALFPI: f32 = 1.5707963267948966;
     sinfull(x + HALFPI)
@@ -226,6 +249,7 @@ pub fn tan(x: f32) -> f32 {
 ///
 /// The range reduction technique used here will be hopelessly inaccurate for |x| >> 1000.
 #[inline]
+#[allow(clippy::approx_constant, clippy::excessive_precision)]
 pub fn tanfull(x: f32) -> f32 {
     const TWOPI: f32 = 6.2831853071795865;
     const INVTWOPI: f32 = 0.15915494309189534;
@@ -236,3 +260,212 @@ pub fn tanfull(x: f32) -> f32 {
 
     sin(xnew) / cos(xnew)
 }
+
+/// Scaled complementary error function `exp(x²) * erfc(x)`.
+///
+/// Unlike [`erfc`], stays accurate for large positive values.
+/// Same form as [`fast::erfcx`](crate::fast::erfcx), with a cubic instead of a polynomial of
+/// degree 9, since the rational form of [`erfc`] cannot be scaled by `exp(x²)` either.
+/// The relative error is below 4% for non-negative values, reached near x = 1.9, and below
+/// 6.5% for negative ones, mostly from [`exp`].
+#[inline]
+pub fn erfcx(x: f32) -> f32 {
+    let z = x.abs();
+    let t = 1.0_f32 / (1.0_f32 + 0.5_f32 * z);
+    let r = t * exp(erfcx_ln_poly(t));
+
+    if x < 0.0_f32 {
+        2.0_f32 * exp(x * x) - r
+    } else {
+        r
+    }
+}
+
+/// Natural logarithm of the complementary error function.
+///
+/// Unlike `ln(erfc(x))`, stays finite for large positive values.
+#[inline]
+pub fn ln_erfc(x: f32) -> f32 {
+    let z = x.abs();
+    let t = 1.0_f32 / (1.0_f32 + 0.5_f32 * z);
+    let p = erfcx_ln_poly(t) - z * z;
+
+    if x < 0.0_f32 {
+        ln(2.0_f32 - t * exp(p))
+    } else {
+        ln(t) + p
+    }
+}
+
+/// `ln(erfcx(z) / t)`, with the cubic minimizing the maximum relative error of
+/// `t exp(cubic)` against erfcx over `t` in (0, 1]. That error of the fitted polynomial alone is
+/// 1.07e-3, while [`erfcx`] adds the error of [`exp`]. `fastapprox-fit faster::erfcx`
+/// re-derives the coefficients.
+#[inline]
+fn erfcx_ln_poly(t: f32) -> f32 {
+    -1.2644419_f32 + t * (0.9602653_f32 + t * (0.6017172_f32 - t * 0.29647146_f32))
+}
+
+/// Riemann zeta function.
+///
+/// Only works for `s > 1`.
+#[inline]
+pub fn zeta(s: f32) -> f32 {
+    1.0_f32 + zeta_tail(s, 2.0_f32)
+}
+
+/// Hurwitz zeta function `ζ(s, q) = Σ (q + k)^(-s)` over `k ≥ 0`.
+///
+/// Only works for `s > 1` and positive `q`.
+/// The error of [`pow`] grows with the exponent, so this is rather inaccurate for large `s`.
+#[inline]
+pub fn hurwitz_zeta(s: f32, q: f32) -> f32 {
+    pow(q, -s) + zeta_tail(s, 1.0_f32 + q)
+}
+
+/// Euler–Maclaurin estimate of `Σ (a + k)^(-s)` over `k ≥ 0`.
+#[inline]
+fn zeta_tail(s: f32, a: f32) -> f32 {
+    pow(a, -s) * (a / (s - 1.0_f32) + 0.5_f32 + 0.083333333_f32 * s / a)
+}
+
+/// Exponential integral E1.
+///
+/// Only works for positive values, with relative error below 15%.
+/// Uses a truncated power series below one and Abramowitz and Stegun 5.1.56 above.
+#[inline]
+#[allow(clippy::excessive_precision)]
+pub fn exp_int_e1(x: f32) -> f32 {
+    if x <= 1.0_f32 {
+        -ln(x) - 0.57721566_f32 + x * (1.0_f32 + x * (-0.25_f32 + x * 0.055555556_f32))
+    } else {
+        let num = 0.250621_f32 + x * (2.334733_f32 + x);
+        let den = 1.681534_f32 + x * (3.330657_f32 + x);
+        exp(-x) * num / (x * den)
+    }
+}
+
+/// Exponential integral Ei.
+///
+/// Works for non-zero values, subnormals included, with relative error below 10% for positive x
+/// and 15% for negative x. Same form as fast::exp_int_ei with lower degrees: the cubic around the root at
+/// x ≈ 0.3725 keeps the error below 2.5e-4, and elsewhere the error of faster::ln dominates.
+/// `fastapprox-fit faster::exp_int_ei` re-derives the constants.
+#[inline]
+pub fn exp_int_ei(x: f32) -> f32 {
+    const EULER: f32 = 0.5772157;
+    const ROOT: f32 = 0.37250742;
+    const ROOT_LO: f32 = -1.23810935e-8;
+    const LN_2_24: f32 = 16.635532;
+
+    if x.abs() < f32::MIN_POSITIVE {
+        // The bit tricks of ln assume a normal float, so subnormals are scaled by 2^24, and the
+        // terms in x are below the resolution of γ + ln |x|.
+        EULER + ln(x.abs() * 16777216.0_f32) - LN_2_24
+    } else if x < 0.0_f32 {
+        -exp_int_e1(-x)
+    } else if (0.25_f32..=0.5_f32).contains(&x) {
+        let d = (x - ROOT) - ROOT_LO;
+        d * (3.895286_f32 + d * (-3.2779267_f32 + d * (6.989554_f32 + d * -14.053596_f32)))
+    } else if x < 6.0_f32 {
+        EULER
+            + ln(x)
+            + x * (0.98354155_f32
+                + x * (0.3306171_f32
+                    + x * (-0.06843888_f32
+                        + x * (0.088484466_f32 + x * (-0.020077167_f32 + x * 0.0026129233_f32)))))
+    } else {
+        let u = 1.0_f32 / x;
+        exp(x) * u * (0.9977408_f32 - 1.3100598_f32 * u) / (1.0_f32 - 2.3499215_f32 * u)
+    }
+}
+
+/// Logarithmic integral li.
+///
+/// Works for positive values other than one. From two upwards the relative error is below 10%,
+/// mostly from faster::ln near x = 2, falls below 3.5% from 2.25 and below 0.4% from 10⁴.
+#[inline]
+pub fn log_int(x: f32) -> f32 {
+    exp_int_ei(ln(x))
+}
+
+/// Modified Bessel function of the first kind of order zero.
+///
+/// Truncated power series for small arguments and leading asymptotic terms otherwise.
+#[inline]
+pub fn bessel_i0(x: f32) -> f32 {
+    const INVSQRT2PI: f32 = 0.3989423;
+
+    let ax = x.abs();
+    if ax < 3.75_f32 {
+        i0_small(ax)
+    } else {
+        INVSQRT2PI * exp(ax) * (1.0_f32 + 0.125_f32 / ax) / ax.sqrt()
+    }
+}
+
+/// Modified Bessel function of the first kind of order one.
+///
+/// Truncated power series for small arguments and leading asymptotic terms otherwise.
+#[inline]
+pub fn bessel_i1(x: f32) -> f32 {
+    const INVSQRT2PI: f32 = 0.3989423;
+
+    let ax = x.abs();
+    let i1 = if ax < 3.75_f32 {
+        ax * i1_small(ax)
+    } else {
+        INVSQRT2PI * exp(ax) * (1.0_f32 - 0.375_f32 / ax) / ax.sqrt()
+    };
+    if x < 0.0_f32 {
+        -i1
+    } else {
+        i1
+    }
+}
+
+/// Natural logarithm of the modified Bessel function of the first kind of order zero.
+///
+/// Does not overflow for large arguments.
+#[inline]
+pub fn ln_bessel_i0(x: f32) -> f32 {
+    const HALFLN2PI: f32 = 0.9189385;
+
+    let ax = x.abs();
+    if ax < 3.75_f32 {
+        ln(i0_small(ax))
+    } else {
+        ax - HALFLN2PI - 0.5_f32 * ln(ax) + 0.125_f32 / ax
+    }
+}
+
+/// Ratio of modified Bessel functions of the first kind I1(x) / I0(x).
+///
+/// This is the mean resultant length of the von Mises distribution with concentration `x`.
+#[inline]
+pub fn bessel_i1_over_i0(x: f32) -> f32 {
+    let ax = x.abs();
+    if ax < 3.75_f32 {
+        x * i1_small(ax) / i0_small(ax)
+    } else {
+        let r = (ax - 0.375_f32) / (ax + 0.125_f32);
+        if x < 0.0_f32 {
+            -r
+        } else {
+            r
+        }
+    }
+}
+
+#[inline]
+fn i0_small(ax: f32) -> f32 {
+    let q = 0.25_f32 * ax * ax;
+    1.0_f32 + q * (1.0_f32 + q * (0.25_f32 + q * (0.027777778_f32 + q * 0.0017361111_f32)))
+}
+
+#[inline]
+fn i1_small(ax: f32) -> f32 {
+    let q = 0.25_f32 * ax * ax;
+    0.5_f32
+        + q * (0.25_f32 + q * (0.041666667_f32 + q * (0.0034722222_f32 + q * 0.00017361111_f32)))
+}
@@ -0,0 +1,138 @@
+//! The kernels follow the variational inference of Hoffman, Blei and Bach, "Online Learning for
+//! Latent Dirichlet Allocation", as implemented by gensim.
+
+/// Stopping rule of the variational E-step.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EStep {
+    /// Maximum number of φ/γ updates.
+    pub max_iter: usize,
+    /// The iteration stops once the mean absolute change of γ falls below this threshold.
+    pub tol: f32,
+}
+
+impl Default for EStep {
+    fn default() -> Self {
+        EStep {
+            max_iter: 50,
+            tol: 0.001,
+        }
+    }
+}
+
+macro_rules! lda_impl {
+    ($tier:ident) => {
+        use super::EStep;
+        use crate::$tier::{digamma, exp};
+
+        /// Expectation `E[ln θ]` of the logarithm of a Dirichlet variable with concentration
+        /// `alpha`, written into `out`.
+        ///
+        /// Only works for positive values.
+        #[inline]
+        pub fn dirichlet_expectation(alpha: &[f32], out: &mut [f32]) {
+            assert_eq!(alpha.len(), out.len());
+            let total = digamma(alpha.iter().sum());
+            for (y, &a) in out.iter_mut().zip(alpha) {
+                *y = digamma(a) - total;
+            }
+        }
+
+        #[inline]
+        fn exp_expectation(gamma: &[f32], out: &mut [f32]) {
+            dirichlet_expectation(gamma, out);
+            for y in out.iter_mut() {
+                *y = exp(*y);
+            }
+        }
+
+        #[inline]
+        fn normalize(
+            counts: &[f32],
+            exp_elog_beta: &[f32],
+            exp_elog_theta: &[f32],
+            phi_norm: &mut [f32],
+        ) {
+            for y in phi_norm.iter_mut() {
+                *y = f32::MIN_POSITIVE;
+            }
+            for (&t, row) in exp_elog_theta
+                .iter()
+                .zip(exp_elog_beta.chunks_exact(counts.len()))
+            {
+                for (y, &b) in phi_norm.iter_mut().zip(row) {
+                    *y += t * b;
+                }
+            }
+            for (y, &c) in phi_norm.iter_mut().zip(counts) {
+                *y = c / *y;
+            }
+        }
+
+        /// Variational E-step of LDA for a single document.
+        ///
+        /// `counts` holds the counts of the distinct words of the document and `exp_elog_beta`
+        /// the matching columns of `exp(E[ln β])`, stored topic-major, so that the entry for
+        /// topic `k` and word `n` is at `k * counts.len() + n`.
+        /// `alpha` is the document-topic prior and `gamma` holds the initial variational
+        /// parameters on entry and the fitted ones on return.
+        ///
+        /// On return `exp_elog_theta` holds `exp(E[ln θ])` for the fitted `gamma` and `phi_norm`
+        /// holds the counts divided by the φ normalizers, which are what the M-step needs to
+        /// accumulate sufficient statistics.
+        ///
+        /// Returns the number of iterations performed.
+        #[inline]
+        pub fn lda_estep(
+            counts: &[f32],
+            exp_elog_beta: &[f32],
+            alpha: &[f32],
+            gamma: &mut [f32],
+            exp_elog_theta: &mut [f32],
+            phi_norm: &mut [f32],
+            params: &EStep,
+        ) -> usize {
+            let topics = alpha.len();
+            assert_eq!(exp_elog_beta.len(), topics * counts.len());
+            assert_eq!(gamma.len(), topics);
+            assert_eq!(exp_elog_theta.len(), topics);
+            assert_eq!(phi_norm.len(), counts.len());
+            if counts.is_empty() {
+                gamma.copy_from_slice(alpha);
+                exp_expectation(gamma, exp_elog_theta);
+                return 0;
+            }
+            exp_expectation(gamma, exp_elog_theta);
+            normalize(counts, exp_elog_beta, exp_elog_theta, phi_norm);
+            for iter in 1..=params.max_iter {
+                let mut change = 0.0_f32;
+                for (((g, &a), &t), row) in gamma
+                    .iter_mut()
+                    .zip(alpha)
+                    .zip(exp_elog_theta.iter())
+                    .zip(exp_elog_beta.chunks_exact(counts.len()))
+                {
+                    let s: f32 = row.iter().zip(phi_norm.iter()).map(|(&b, &r)| b * r).sum();
+                    let updated = a + t * s;
+                    change += (updated - *g).abs();
+                    *g = updated;
+                }
+                exp_expectation(gamma, exp_elog_theta);
+                normalize(counts, exp_elog_beta, exp_elog_theta, phi_norm);
+                if change < params.tol * topics as f32 {
+                    return iter;
+                }
+            }
+            params.max_iter
+        }
+    };
+}
+
+/// LDA kernels built on [`crate::fast`] approximations.
+pub mod fast {
+    lda_impl!(fast);
+}
+
+/// LDA kernels built on [`crate::faster`] approximations.
+pub mod faster {
+    lda_impl!(faster);
+}
@@ -9,6 +9,11 @@
 //! E.g. in [Vowpal Wabbit](https://github.com/JohnLangford/vowpal_wabbit) this is one of the many clever tricks used to reach it's incredible training speed.
 //!
 //! Module names `fast` and `faster` come from the original work, and represent sets of the same algorithms with different speed-accuracy levels.
+//! The `precise` module has the same functions, computed in double precision to within one unit in the last place.
+//! The `ulp` module names them, and the `fast` functions with a bounded relative error, by their guaranteed error.
+//!
+//! The modules built on the approximations, such as `dist`, `activation` or `entropy`, have a `fast` and a `faster` submodule with the same functions, built on the approximations of that tier.
+//! Their functions taking several slices panic if the lengths do not match.
 //!
 //! Although approximate functions could give you some speedup (but not necessarily!), some warnings should be provided:
 //!
@@ -93,5 +98,57 @@ pub mod fast;
 /// Faster approximations with considerable error.
 pub mod faster;
 
+/// Precise approximations within one unit in the last place.
+pub mod precise;
+
+/// The approximations under names stating their error bound in units in the last place.
+pub mod ulp;
+
+/// Base 2 exponential and logarithm with a polynomial of configurable degree.
+pub mod poly;
+
 /// Raw bits manipulations (public for pedagogical reasons).
 pub mod bits;
+
+/// Log-densities of common distributions.
+pub mod dist;
+
+/// Neural network activation functions.
+pub mod activation;
+
+/// Numerically stable softmax and log-sum-exp over slices.
+pub mod softmax;
+
+/// Loss functions with gradients for online learners.
+pub mod loss;
+
+/// Adaptive optimizer update kernels over slices.
+pub mod update;
+
+/// Dirichlet expectations and the variational E-step of latent Dirichlet allocation.
+pub mod lda;
+
+/// Numbers stored in log space, for probabilities that would underflow.
+pub mod logspace;
+
+/// Entropy, cross-entropy and divergences of discrete distributions.
+pub mod entropy;
+
+/// Double precision references of the approximated functions.
+pub mod reference;
+
+/// Domains, references and error bounds of the approximations.
+pub mod registry;
+
+/// Chebyshev approximations of user functions, fitted at runtime.
+pub mod chebyshev;
+
+/// Lookup tables with nearest, linear or cubic interpolation.
+pub mod lut;
+
+/// Rust source for fitted approximations.
+pub mod codegen;
+
+/// Random variate samplers.
+#[cfg(feature = "rand_core")]
+pub mod sample;
@@ -0,0 +1,219 @@
+//! Besides [`fast`] and [`faster`], the [`LogF32`] type is available in the [`exact`] tier
+//! built on `std`.
+//! The tiers have the same interface, so switching the import to [`exact`] turns the
+//! approximations off, e.g. to check whether they are responsible for a change in results.
+//!
+//! Negative infinity represents zero, so sums and products involving zero are exact in every
+//! tier.
+//!
+//! [`fast`]: crate::logspace::fast
+//! [`faster`]: crate::logspace::faster
+//! [`exact`]: crate::logspace::exact
+//! [`LogF32`]: crate::logspace::fast::LogF32
+
+macro_rules! logspace_impl {
+    () => {
+        use core::iter::{Product, Sum};
+        use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign};
+
+        /// Non-negative number stored as its natural logarithm.
+        ///
+        /// Addition is computed with log-add-exp, multiplication and division by adding and
+        /// subtracting the logarithms without approximation.
+        #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+        pub struct LogF32(f32);
+
+        impl LogF32 {
+            /// Zero, stored as negative infinity.
+            pub const ZERO: LogF32 = LogF32(f32::NEG_INFINITY);
+
+            /// One, stored as zero.
+            pub const ONE: LogF32 = LogF32(0.0);
+
+            /// Converts a non-negative number to log space.
+            #[inline]
+            pub fn new(x: f32) -> Self {
+                if x > 0.0_f32 {
+                    LogF32(ln(x))
+                } else {
+                    Self::ZERO
+                }
+            }
+
+            /// Wraps a natural logarithm.
+            #[inline]
+            pub fn from_ln(ln: f32) -> Self {
+                LogF32(ln)
+            }
+
+            /// Natural logarithm of the number.
+            #[inline]
+            pub fn ln(self) -> f32 {
+                self.0
+            }
+
+            /// Converts the number back from log space.
+            #[inline]
+            pub fn value(self) -> f32 {
+                if self.0 == f32::NEG_INFINITY {
+                    0.0_f32
+                } else {
+                    exp(self.0)
+                }
+            }
+
+            /// Whether the number is zero.
+            #[inline]
+            pub fn is_zero(self) -> bool {
+                self.0 == f32::NEG_INFINITY
+            }
+        }
+
+        impl Default for LogF32 {
+            #[inline]
+            fn default() -> Self {
+                Self::ZERO
+            }
+        }
+
+        impl From<f32> for LogF32 {
+            #[inline]
+            fn from(x: f32) -> Self {
+                Self::new(x)
+            }
+        }
+
+        impl From<LogF32> for f32 {
+            #[inline]
+            fn from(x: LogF32) -> Self {
+                x.value()
+            }
+        }
+
+        impl Add for LogF32 {
+            type Output = LogF32;
+
+            /// Log-add-exp `ln(exp(a) + exp(b))`.
+            #[inline]
+            fn add(self, other: LogF32) -> LogF32 {
+                let (hi, lo) = if self.0 >= other.0 {
+                    (self.0, other.0)
+                } else {
+                    (other.0, self.0)
+                };
+                if lo == f32::NEG_INFINITY {
+                    LogF32(hi)
+                } else {
+                    LogF32(hi + softplus(lo - hi))
+                }
+            }
+        }
+
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        impl Mul for LogF32 {
+            type Output = LogF32;
+
+            #[inline]
+            fn mul(self, other: LogF32) -> LogF32 {
+                LogF32(self.0 + other.0)
+            }
+        }
+
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        impl Div for LogF32 {
+            type Output = LogF32;
+
+            #[inline]
+            fn div(self, other: LogF32) -> LogF32 {
+                LogF32(self.0 - other.0)
+            }
+        }
+
+        impl AddAssign for LogF32 {
+            #[inline]
+            fn add_assign(&mut self, other: LogF32) {
+                *self = *self + other;
+            }
+        }
+
+        #[allow(clippy::suspicious_op_assign_impl)]
+        impl MulAssign for LogF32 {
+            #[inline]
+            fn mul_assign(&mut self, other: LogF32) {
+                self.0 += other.0;
+            }
+        }
+
+        #[allow(clippy::suspicious_op_assign_impl)]
+        impl DivAssign for LogF32 {
+            #[inline]
+            fn div_assign(&mut self, other: LogF32) {
+                self.0 -= other.0;
+            }
+        }
+
+        impl Sum for LogF32 {
+            #[inline]
+            fn sum<I: Iterator<Item = LogF32>>(iter: I) -> LogF32 {
+                iter.fold(LogF32::ZERO, Add::add)
+            }
+        }
+
+        impl<'a> Sum<&'a LogF32> for LogF32 {
+            #[inline]
+            fn sum<I: Iterator<Item = &'a LogF32>>(iter: I) -> LogF32 {
+                iter.copied().sum()
+            }
+        }
+
+        impl Product for LogF32 {
+            #[inline]
+            fn product<I: Iterator<Item = LogF32>>(iter: I) -> LogF32 {
+                iter.fold(LogF32::ONE, Mul::mul)
+            }
+        }
+
+        impl<'a> Product<&'a LogF32> for LogF32 {
+            #[inline]
+            fn product<I: Iterator<Item = &'a LogF32>>(iter: I) -> LogF32 {
+                iter.copied().product()
+            }
+        }
+    };
+}
+
+/// Log-space numbers built on [`crate::fast`] approximations.
+pub mod fast {
+    use crate::activation::fast::softplus;
+    use crate::fast::{exp, ln};
+
+    logspace_impl!();
+}
+
+/// Log-space numbers built on [`crate::faster`] approximations.
+pub mod faster {
+    use crate::activation::faster::softplus;
+    use crate::faster::{exp, ln};
+
+    logspace_impl!();
+}
+
+/// Log-space numbers built on `std`, for reference.
+pub mod exact {
+    #[inline]
+    fn exp(x: f32) -> f32 {
+        x.exp()
+    }
+
+    #[inline]
+    fn ln(x: f32) -> f32 {
+        x.ln()
+    }
+
+    #[inline]
+    fn softplus(x: f32) -> f32 {
+        x.exp().ln_1p()
+    }
+
+    logspace_impl!();
+}
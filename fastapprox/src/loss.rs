@@ -0,0 +1,91 @@
+//! Each function returns the loss together with its derivative with respect to the prediction,
+//! as used by online learners such as Vowpal Wabbit.
+
+macro_rules! loss_impl {
+    ($tier:ident) => {
+        use crate::activation::$tier::softplus_with_grad;
+        use crate::$tier::{exp, ln};
+
+        /// Probabilities are clamped to `[PROB_EPS, 1 - PROB_EPS]` to keep the log-loss finite.
+        pub const PROB_EPS: f32 = 1e-7;
+
+        /// Logistic loss `ln(1 + exp(-label * prediction))` and its derivative.
+        ///
+        /// `label` is -1 or 1, `prediction` is a raw margin.
+        /// Stays finite and accurate for arbitrarily large margins.
+        #[inline]
+        pub fn logistic_loss(prediction: f32, label: f32) -> (f32, f32) {
+            let (loss, s) = softplus_with_grad(-label * prediction);
+            (loss, -label * s)
+        }
+
+        /// Log-loss (binary cross-entropy) of a predicted probability and its derivative.
+        ///
+        /// `label` is 0 or 1, `prediction` is a probability clamped to
+        /// `[PROB_EPS, 1 - PROB_EPS]`.
+        #[inline]
+        pub fn log_loss(prediction: f32, label: f32) -> (f32, f32) {
+            let p = prediction.clamp(PROB_EPS, 1.0_f32 - PROB_EPS);
+            let q = 1.0_f32 - p;
+            (
+                -label * ln(p) - (1.0_f32 - label) * ln(q),
+                (p - label) / (p * q),
+            )
+        }
+
+        /// Poisson loss `exp(prediction) - label * prediction` and its derivative.
+        ///
+        /// `prediction` is the logarithm of the rate, `label` is a non-negative count.
+        /// The constant `ln(label!)` is omitted.
+        #[inline]
+        pub fn poisson_loss(prediction: f32, label: f32) -> (f32, f32) {
+            let rate = exp(prediction.min(88.0_f32));
+            (rate - label * prediction, rate - label)
+        }
+
+        /// Quantile (pinball) loss for the quantile `tau` in (0, 1) and its derivative.
+        ///
+        /// Exact, provided for completeness.
+        #[inline]
+        pub fn quantile_loss(prediction: f32, label: f32, tau: f32) -> (f32, f32) {
+            let r = label - prediction;
+            if r >= 0.0_f32 {
+                (tau * r, -tau)
+            } else {
+                ((tau - 1.0_f32) * r, 1.0_f32 - tau)
+            }
+        }
+
+        /// Squared loss `(prediction - label)²` and its derivative.
+        ///
+        /// Exact, provided for completeness.
+        #[inline]
+        pub fn squared_loss(prediction: f32, label: f32) -> (f32, f32) {
+            let r = prediction - label;
+            (r * r, 2.0_f32 * r)
+        }
+
+        /// Hinge loss `max(0, 1 - label * prediction)` and its subgradient.
+        ///
+        /// `label` is -1 or 1. Exact, provided for completeness.
+        #[inline]
+        pub fn hinge_loss(prediction: f32, label: f32) -> (f32, f32) {
+            let m = 1.0_f32 - label * prediction;
+            if m > 0.0_f32 {
+                (m, -label)
+            } else {
+                (0.0_f32, 0.0_f32)
+            }
+        }
+    };
+}
+
+/// Losses built on [`crate::fast`] approximations.
+pub mod fast {
+    loss_impl!(fast);
+}
+
+/// Losses built on [`crate::faster`] approximations.
+pub mod faster {
+    loss_impl!(faster);
+}
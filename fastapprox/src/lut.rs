@@ -0,0 +1,168 @@
+//! Lookup tables of function values with interpolation between the entries.
+//!
+//! A [`Lut`] samples a function at `N` evenly spaced points of a bounded interval, and trades
+//! memory for accuracy through `N`. For `sigmoid` on [-8, 8] with 256 entries,
+//! [`Interpolation::Linear`] is two orders of magnitude more accurate than
+//! [`faster::sigmoid`](crate::faster::sigmoid), and [`Interpolation::Cubic`] one order more
+//! accurate than [`fast::sigmoid`](crate::fast::sigmoid); the `sigmoid_lut` and `tanh_lut`
+//! benches compare their speed with the tiers.
+//!
+//! Tables are built at startup from any function with [`Lut::new`], or at compile time from
+//! precomputed values with the `const fn` [`Lut::from_table`].
+//!
+//! # Examples
+//!
+//! ```
+//! use fastapprox::lut::{Edge, Interpolation, Lut};
+//!
+//! let sigmoid = Lut::<256>::new(-8.0, 8.0, |x| 1.0 / (1.0 + (-x).exp()))
+//!     .with_interpolation(Interpolation::Cubic);
+//! assert!((sigmoid.eval(0.3) - 0.5744425).abs() < 1e-6);
+//! assert_eq!(sigmoid.eval(100.0), sigmoid.eval(8.0));
+//!
+//! const SQUARES: Lut<5> = Lut::from_table(0.0, 4.0, [0.0, 1.0, 4.0, 9.0, 16.0])
+//!     .with_edge(Edge::Extrapolate);
+//! assert_eq!(SQUARES.eval(2.5), 6.5);
+//! assert_eq!(SQUARES.eval(5.0), 23.0);
+//! ```
+//!
+//! [`Lut`]: crate::lut::Lut
+//! [`Lut::new`]: crate::lut::Lut::new
+//! [`Lut::from_table`]: crate::lut::Lut::from_table
+//! [`Interpolation::Linear`]: crate::lut::Interpolation::Linear
+//! [`Interpolation::Cubic`]: crate::lut::Interpolation::Cubic
+
+/// How values between the entries of a [`Lut`] are computed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Value of the nearest entry.
+    Nearest,
+    /// Straight line between the two neighbouring entries.
+    Linear,
+    /// Cubic Hermite spline through the entries, with slopes from finite differences
+    /// (Catmull-Rom), which is continuous with a continuous derivative.
+    Cubic,
+}
+
+/// How a [`Lut`] handles inputs outside its interval.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Edge {
+    /// Value of the first or last entry.
+    Clamp,
+    /// Straight line through the two entries at that end, whatever the interpolation.
+    Extrapolate,
+}
+
+/// Table of `N` values of a function at evenly spaced points of `[lo, hi]`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Lut<const N: usize> {
+    lo: f32,
+    hi: f32,
+    scale: f32,
+    table: [f32; N],
+    interpolation: Interpolation,
+    edge: Edge,
+}
+
+impl<const N: usize> Lut<N> {
+    /// Tabulates `f` at `N` evenly spaced points of `[lo, hi]`, including both ends, with
+    /// linear interpolation and clamped edges.
+    ///
+    /// Panics unless `N >= 2` and `lo < hi`.
+    pub fn new(lo: f32, hi: f32, f: impl Fn(f32) -> f32) -> Self {
+        let mut table = [0.0_f32; N];
+        for (i, t) in table.iter_mut().enumerate() {
+            let x = lo as f64 + (hi as f64 - lo as f64) * i as f64 / (N - 1).max(1) as f64;
+            *t = f(x as f32);
+        }
+        Self::from_table(lo, hi, table)
+    }
+
+    /// Wraps values already computed at `N` evenly spaced points of `[lo, hi]`, with linear
+    /// interpolation and clamped edges.
+    ///
+    /// Panics unless `N >= 2` and `lo < hi`.
+    pub const fn from_table(lo: f32, hi: f32, table: [f32; N]) -> Self {
+        assert!(N >= 2, "a table needs at least two entries");
+        assert!(lo < hi, "empty interval");
+        Lut {
+            lo,
+            hi,
+            scale: (N - 1) as f32 / (hi - lo),
+            table,
+            interpolation: Interpolation::Linear,
+            edge: Edge::Clamp,
+        }
+    }
+
+    /// The same table with another interpolation.
+    pub const fn with_interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// The same table with another handling of the edges.
+    pub const fn with_edge(mut self, edge: Edge) -> Self {
+        self.edge = edge;
+        self
+    }
+
+    /// The interval `[lo, hi]`.
+    pub fn domain(&self) -> (f32, f32) {
+        (self.lo, self.hi)
+    }
+
+    /// The tabulated values.
+    pub fn table(&self) -> &[f32; N] {
+        &self.table
+    }
+
+    /// How values between the entries are computed.
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    /// How inputs outside the interval are handled.
+    pub fn edge(&self) -> Edge {
+        self.edge
+    }
+
+    /// Interpolated value at `x`.
+    #[inline]
+    pub fn eval(&self, x: f32) -> f32 {
+        let t = &self.table;
+        let last = N - 1;
+        // Position in units of the spacing of the entries.
+        let mut u = (x - self.lo) * self.scale;
+        match self.edge {
+            Edge::Clamp => u = u.clamp(0.0_f32, last as f32),
+            Edge::Extrapolate if !(0.0_f32..=last as f32).contains(&u) => {
+                let i = if u < 0.0_f32 { 0 } else { last - 1 };
+                return t[i] + (u - i as f32) * (t[i + 1] - t[i]);
+            }
+            Edge::Extrapolate => {}
+        }
+        if u.is_nan() {
+            return u;
+        }
+        // Casts saturate, so that `u` in [0, N - 1] gives `i` in [0, N - 2] and `f` in [0, 1].
+        let i = (u as usize).min(last - 1);
+        let f = u - i as f32;
+        match self.interpolation {
+            Interpolation::Nearest => t[((u + 0.5_f32) as usize).min(last)],
+            Interpolation::Linear => t[i] + f * (t[i + 1] - t[i]),
+            Interpolation::Cubic => {
+                let (y0, y1) = (t[i], t[i + 1]);
+                let d = y1 - y0;
+                // One-sided differences at the ends of the table.
+                let m0 = if i > 0 { 0.5_f32 * (y1 - t[i - 1]) } else { d };
+                let m1 = if i + 2 <= last {
+                    0.5_f32 * (t[i + 2] - y0)
+                } else {
+                    d
+                };
+                y0 + f * (m0 + f * (3.0_f32 * d - 2.0_f32 * m0 - m1 + f * (m0 + m1 - 2.0_f32 * d)))
+            }
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! Base 2 exponential and logarithm with a polynomial of configurable degree.
+//!
+//! [`exp2_deg`] and [`log2_deg`] reduce their argument with bit manipulations like
+//! [`fast::pow2`](crate::fast::pow2) and [`fast::log2`](crate::fast::log2), and approximate the
+//! rest with a polynomial of degree `N` from 1 to 6, so that accuracy can be traded for speed in
+//! finer steps than the two tiers. The coefficients are minimax fits, re-optimized for rounding
+//! to `f32`. Other degrees fail to compile.
+//!
+//! | `N` | [`exp2_deg`] relative error | [`log2_deg`] absolute error | relative error on [½, 2] |
+//! |-----|-----------------------------|-----------------------------|--------------------------|
+//! | 1   | 3.0e-2                      | 8.6e-2                      | 1.8e-1                   |
+//! | 2   | 1.8e-3                      | 5.7e-3                      | 2.9e-2                   |
+//! | 3   | 7.5e-5                      | 8.6e-4                      | 3.4e-3                   |
+//! | 4   | 2.7e-6                      | 1.1e-4                      | 7.3e-4                   |
+//! | 5   | 1.8e-7                      | 1.9e-5                      | 1.1e-4                   |
+//! | 6   | 1.1e-7                      | 6.0e-6                      | 1.8e-5                   |
+//!
+//! The errors are over all powers from -126 to 127 and all positive normal floats. At the
+//! highest degrees, the absolute error of `log2_deg` for large or small `x` comes from rounding
+//! the result, whose magnitude is then above 64.
+//!
+//! Each degree adds one multiply-add to the cost. In the `pow2_deg` and `log2_deg` benches,
+//! degree 6 takes less than twice as long as degree 1. Degree 3 of `exp2_deg` matches
+//! [`fast::pow2`](crate::fast::pow2) in both speed and accuracy. The `faster` tier is still
+//! the cheapest. At degree 6, `exp2_deg` is as accurate as `f32` allows.
+//!
+//! # Examples
+//!
+//! ```
+//! use fastapprox::poly::{exp2_deg, log2_deg};
+//!
+//! assert!((exp2_deg::<4>(0.5) - 2f32.sqrt()).abs() < 1e-5);
+//! assert!((log2_deg::<6>(10.0) - 10f32.log2()).abs() < 1e-5);
+//! ```
+//!
+//! ```compile_fail
+//! fastapprox::poly::exp2_deg::<7>(1.0);
+//! ```
+//!
+//! [`exp2_deg`]: crate::poly::exp2_deg
+//! [`log2_deg`]: crate::poly::log2_deg
+
+use crate::bits::{from_bits, to_bits};
+
+/// Coefficients of `2^f` on [0, 1], from the constant term up, by degree.
+// The linear coefficients are fitted, and tend to ln 2 as the degree grows.
+#[allow(clippy::approx_constant)]
+const EXP2: [&[f32]; 6] = [
+    &[0.97017926, 0.97017807],
+    &[1.0017246, 0.6576367, 0.33718935],
+    &[0.9999252, 0.6958335, 0.22606725, 0.07802451],
+    &[1.0000026, 0.6930038, 0.24144274, 0.05201147, 0.013534166],
+    &[
+        0.99999994,
+        0.693153,
+        0.24015366,
+        0.055826314,
+        0.0089893425,
+        0.0018775762,
+    ],
+    &[
+        1.0,
+        0.693147,
+        0.24022982,
+        0.055483334,
+        0.00967884,
+        0.0012439699,
+        0.00021702221,
+    ],
+];
+
+/// Coefficients of `log2(1 + t) / t` on [√½ - 1, √2 - 1], from the constant term up, by degree
+/// of `log2(1 + t)`.
+const LOG2: [&[f32]; 6] = [
+    &[1.4142114],
+    &[1.483122, -0.69915044],
+    &[1.4451522, -0.7540803, 0.4450683],
+    &[1.4417605, -0.72490406, 0.5175099, -0.32962942],
+    &[1.442578, -0.72024155, 0.48668632, -0.39457574, 0.2526598],
+    &[
+        1.4427135,
+        -0.7211319,
+        0.479348,
+        -0.3674901,
+        0.3221551,
+        -0.20659152,
+    ],
+];
+
+/// Bits of √½, the lower end of the reduced mantissa in [`log2_deg`].
+const SQRT_HALF_BITS: u32 = 0x3f3504f3;
+
+#[inline]
+fn horner(c: &[f32], x: f32) -> f32 {
+    c.iter().rev().fold(0.0_f32, |acc, &k| k + x * acc)
+}
+
+/// Raises 2 to a floating point power, with a polynomial of degree `N` from 1 to 6.
+///
+/// Powers below -126 are clamped to -126, and powers of 128 and above give infinity.
+#[inline]
+pub fn exp2_deg<const N: usize>(p: f32) -> f32 {
+    const { assert!(N >= 1 && N <= 6, "degree must be from 1 to 6") };
+    let p = p.clamp(-126.0_f32, 128.0_f32);
+    // Floor of `p`, with the fractional part in [0, 1).
+    let w = p as i32;
+    let w = w - (p < w as f32) as i32;
+    let f = p - w as f32;
+    from_bits(((w + 127) as u32) << 23) * horner(EXP2[N - 1], f)
+}
+
+/// Base 2 logarithm, with a polynomial of degree `N` from 1 to 6.
+///
+/// Only works for positive normal values.
+#[inline]
+pub fn log2_deg<const N: usize>(x: f32) -> f32 {
+    const { assert!(N >= 1 && N <= 6, "degree must be from 1 to 6") };
+    // x = 2^e m, with m in [√½, √2).
+    let vx = to_bits(x).wrapping_sub(SQRT_HALF_BITS);
+    let e = (vx as i32) >> 23;
+    let t = from_bits((vx & 0x007FFFFF) + SQRT_HALF_BITS) - 1.0_f32;
+    e as f32 + t * horner(LOG2[N - 1], t)
+}
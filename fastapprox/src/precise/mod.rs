@@ -0,0 +1,980 @@
+//! Approximations within one unit in the last place, for when convergence is sensitive to the
+//! error of the `fast` tier.
+//!
+//! Every function evaluates in double precision with its own polynomials and range reductions,
+//! without calling into the system math library, and rounds the result to `f32` once. The
+//! only exception is the square root, which compiles to a single instruction. Exponentials and
+//! logarithms reduce their argument exactly by powers of two, and trigonometric functions
+//! reduce theirs by π/2 with enough bits of 2/π to stay accurate over all finite `f32` values,
+//! so `sinfull` and friends have no limit on |x| unlike the other tiers.
+//!
+//! Against [`crate::reference`], the error of every function stays within one unit in the last
+//! place (ULP) on every 8191st float of its domain in [`crate::registry`], which the tests
+//! check, and almost all results are correctly rounded. The tests also sweep the trigonometric
+//! functions over the whole range of finite floats, at a stride sixteen times larger.
+//!
+//! Iterative functions such as [`lambertw`] and [`erf_inv`] cost a few exponentials each, and
+//! the special functions summing series cost tens of multiplications, so this tier is the
+//! slowest of the three.
+//!
+//! # Examples
+//!
+//! ```
+//! use fastapprox::precise;
+//!
+//! assert_eq!(precise::exp(1.0), std::f32::consts::E);
+//! assert_eq!(precise::sinfull(1e30), (1e30_f32 as f64).sin() as f32);
+//! ```
+//!
+//! [`lambertw`]: crate::precise::lambertw
+//! [`erf_inv`]: crate::precise::erf_inv
+
+use std::f64::consts::{FRAC_2_SQRT_PI, FRAC_PI_2, FRAC_PI_4, LN_2, LOG2_E, PI};
+
+use crate::reference::{DIGAMMA_ROOT, DIGAMMA_ROOT_LO, EULER};
+
+/// High part of ln 2, with enough trailing zeros for `k * LN2_HI` to be exact for |k| < 2^11.
+const LN2_HI: f64 = 6.931471803691238e-1;
+/// ln 2 - `LN2_HI`.
+const LN2_LO: f64 = 1.9082149292705877e-10;
+
+/// Bits of √½, the lower end of the reduced mantissa of logarithms.
+const SQRT_HALF_BITS: u64 = 0x3fe6a09e667f3bcd;
+
+/// Taylor coefficients of `exp(r)`, from the constant term up.
+const EXP: [f64; 14] = [
+    1.0,
+    1.0,
+    1.0 / 2.0,
+    1.0 / 6.0,
+    1.0 / 24.0,
+    1.0 / 120.0,
+    1.0 / 720.0,
+    1.0 / 5040.0,
+    1.0 / 40320.0,
+    1.0 / 362880.0,
+    1.0 / 3628800.0,
+    1.0 / 39916800.0,
+    1.0 / 479001600.0,
+    1.0 / 6227020800.0,
+];
+
+/// Taylor coefficients of `2 atanh(s) / s` in `s²`, where `ln(m) = 2 atanh((m - 1) / (m + 1))`.
+const ATANH: [f64; 11] = [
+    2.0,
+    2.0 / 3.0,
+    2.0 / 5.0,
+    2.0 / 7.0,
+    2.0 / 9.0,
+    2.0 / 11.0,
+    2.0 / 13.0,
+    2.0 / 15.0,
+    2.0 / 17.0,
+    2.0 / 19.0,
+    2.0 / 21.0,
+];
+
+/// Taylor coefficients of `(sin(r) - r) / r³` in `r²`.
+const SIN: [f64; 7] = [
+    -1.0 / 6.0,
+    1.0 / 120.0,
+    -1.0 / 5040.0,
+    1.0 / 362880.0,
+    -1.0 / 39916800.0,
+    1.0 / 6227020800.0,
+    -1.0 / 1307674368000.0,
+];
+
+/// Taylor coefficients of `(cos(r) - 1) / r²` in `r²`.
+const COS: [f64; 8] = [
+    -1.0 / 2.0,
+    1.0 / 24.0,
+    -1.0 / 720.0,
+    1.0 / 40320.0,
+    -1.0 / 3628800.0,
+    1.0 / 479001600.0,
+    -1.0 / 87178291200.0,
+    1.0 / 20922789888000.0,
+];
+
+/// Bits of 2/π after the point, for the reduction of trigonometric arguments.
+const TWO_OVER_PI: [u32; 8] = [
+    0xa2f9836e, 0x4e441529, 0xfc2757d1, 0xf534ddc0, 0xdb629599, 0x3c439041, 0xfe5163ab, 0xdebbc561,
+];
+
+/// Number of bits of 2/π multiplied with the mantissa of a trigonometric argument.
+const WINDOW: u32 = 100;
+
+/// Taylor coefficients of `erf(x) / x` in `x²`.
+const ERF: [f64; 18] = [
+    FRAC_2_SQRT_PI,
+    -0.37612638903183754,
+    0.11283791670955126,
+    -0.026866170645131252,
+    0.005223977625442188,
+    -0.0008548327023450853,
+    0.00012055332981789664,
+    -1.492565035840625e-05,
+    1.6462114365889248e-06,
+    -1.6365844691234924e-07,
+    1.4807192815879218e-08,
+    -1.2290555301717928e-09,
+    9.422759064650411e-11,
+    -6.7113668551641105e-12,
+    4.4632242632864775e-13,
+    -2.7835162072109215e-14,
+    1.6342614095367152e-15,
+    -9.063970842808673e-17,
+];
+
+/// Chebyshev coefficients of `ln(erfcx(z) / t)` in `2t - 1`, where `t = 2 / (2 + z)` maps the
+/// non-negative `z` to (0, 1].
+const ERFCX: [f64; 24] = [
+    -0.6513268598908547,
+    0.6419697923564902,
+    0.019476473204185836,
+    -0.009561514786808632,
+    -0.0009465953444820369,
+    0.00036683949785276145,
+    4.252332480690777e-05,
+    -2.0278578112534242e-05,
+    -1.6242900046470256e-06,
+    1.3036558355805232e-06,
+    1.5626441722066142e-08,
+    -8.523809591492654e-08,
+    6.5290544390988515e-09,
+    5.059343495551469e-09,
+    -9.91364156493033e-10,
+    -2.273651222931836e-10,
+    9.646791102015527e-11,
+    2.3940380830391146e-12,
+    -6.886027526497553e-12,
+    8.944879273090725e-13,
+    3.130921399342958e-13,
+    -1.1270822361367252e-13,
+    3.810905255189232e-16,
+    7.106097613609237e-15,
+];
+
+/// `B_2k / (2k (2k - 1))`, the coefficients of Stirling's series for `ln Γ`.
+const STIRLING: [f64; 7] = [
+    1.0 / 12.0,
+    -1.0 / 360.0,
+    1.0 / 1260.0,
+    -1.0 / 1680.0,
+    1.0 / 1188.0,
+    -691.0 / 360360.0,
+    1.0 / 156.0,
+];
+
+/// `(-1)^k ζ(k) / k` from k = 2, the coefficients of the power series of `ln Γ(1 + z)` after
+/// its linear term.
+const LN_GAMMA_1P: [f64; 18] = [
+    0.8224670334241132,
+    -0.40068563438653143,
+    0.27058080842778454,
+    -0.20738555102867398,
+    0.1695571769974082,
+    -0.1440498967688461,
+    0.12550966952474304,
+    -0.11133426586956469,
+    0.1000994575127818,
+    -0.09095401714582904,
+    0.083353840546109,
+    -0.0769325164113522,
+    0.07143294629536133,
+    -0.06666870588242046,
+    0.06250095514121304,
+    -0.058823978658684585,
+    0.055555767627403614,
+    -0.05263167937961666,
+];
+
+/// `(-1)^(k + 1) ζ(k + 1, x0)` from k = 1, the coefficients of the Taylor series of the digamma
+/// function around its positive zero `x0`.
+const DIGAMMA_TAYLOR: [f64; 13] = [
+    0.9676722454476212,
+    -0.4427631689835921,
+    0.258499760955651,
+    -0.16394270544240652,
+    0.10782405069126237,
+    -0.07219956125645471,
+    0.04880428816414311,
+    -0.03316112647484736,
+    0.022597648232218104,
+    -0.01542476590494896,
+    0.010538791616612175,
+    -0.007204534386356869,
+    0.004926781395729853,
+];
+
+/// `B_2k / 2k`, the coefficients of the asymptotic series of the digamma function.
+const DIGAMMA: [f64; 7] = [
+    1.0 / 12.0,
+    -1.0 / 120.0,
+    1.0 / 252.0,
+    -1.0 / 240.0,
+    1.0 / 132.0,
+    -691.0 / 32760.0,
+    1.0 / 12.0,
+];
+
+/// `B_2k / (2k)!`, the coefficients of the Euler–Maclaurin formula.
+const BERNOULLI: [f64; 7] = [
+    1.0 / 12.0,
+    -1.0 / 720.0,
+    1.0 / 30240.0,
+    -1.0 / 1209600.0,
+    1.0 / 47900160.0,
+    -691.0 / 1307674368000.0,
+    1.0 / 74724249600.0,
+];
+
+/// Arguments of Bessel functions above which the asymptotic expansion replaces the power series.
+const BESSEL_SPLIT: f64 = 15.0;
+
+#[inline]
+fn horner(c: &[f64], x: f64) -> f64 {
+    c.iter().rev().fold(0.0, |acc, &k| k + x * acc)
+}
+
+/// Nearest integer, for values well within the range of `i64`.
+#[inline]
+fn round(x: f64) -> i64 {
+    (x + if x < 0.0 { -0.5 } else { 0.5 }) as i64
+}
+
+/// `2^k`, for `k` from -1022 to 1023.
+#[inline]
+fn pow2i(k: i64) -> f64 {
+    f64::from_bits(((k + 1023) as u64) << 52)
+}
+
+/// `2^k exp(r)` for |r| ≤ ln(2) / 2, with the scale split in two so that `k` may range from
+/// -2044 to 2046.
+#[inline]
+fn scaled_exp(k: i64, r: f64) -> f64 {
+    let h = k / 2;
+    horner(&EXP, r) * pow2i(h) * pow2i(k - h)
+}
+
+#[inline]
+fn exp_f64(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+    if x > 709.8 {
+        return f64::INFINITY;
+    }
+    if x < -745.2 {
+        return 0.0;
+    }
+    let k = round(x * LOG2_E);
+    let r = (x - k as f64 * LN2_HI) - k as f64 * LN2_LO;
+    scaled_exp(k, r)
+}
+
+#[inline]
+fn exp2_f64(p: f64) -> f64 {
+    if p.is_nan() {
+        return p;
+    }
+    if p > 1024.0 {
+        return f64::INFINITY;
+    }
+    if p < -1075.0 {
+        return 0.0;
+    }
+    let k = round(p);
+    scaled_exp(k, (p - k as f64) * LN_2)
+}
+
+/// `exp(x) - 1`, without cancellation for small `x`.
+#[inline]
+fn expm1_f64(x: f64) -> f64 {
+    if x.abs() < 0.5 * LN_2 {
+        x * horner(&EXP[1..], x)
+    } else {
+        exp_f64(x) - 1.0
+    }
+}
+
+/// Splits a positive finite `x` into `e` and `ln(m)`, with `x = 2^e m` and m in [√½, √2).
+#[inline]
+fn ln_parts(x: f64) -> (f64, f64) {
+    let (x, bias) = if x < f64::MIN_POSITIVE {
+        (x * pow2i(54), -54.0)
+    } else {
+        (x, 0.0)
+    };
+    let v = x.to_bits().wrapping_sub(SQRT_HALF_BITS);
+    let e = ((v as i64) >> 52) as f64 + bias;
+    let m = f64::from_bits((v & 0x000f_ffff_ffff_ffff) + SQRT_HALF_BITS);
+    let s = (m - 1.0) / (m + 1.0);
+    (e, s * horner(&ATANH, s * s))
+}
+
+/// Logarithm of the special values, or `None` for positive finite `x`.
+#[inline]
+fn ln_special(x: f64) -> Option<f64> {
+    if x.is_nan() || x < 0.0 {
+        Some(f64::NAN)
+    } else if x == 0.0 {
+        Some(f64::NEG_INFINITY)
+    } else if x == f64::INFINITY {
+        Some(x)
+    } else {
+        None
+    }
+}
+
+#[inline]
+fn ln_f64(x: f64) -> f64 {
+    if let Some(y) = ln_special(x) {
+        return y;
+    }
+    let (e, ln_m) = ln_parts(x);
+    e * LN2_HI + (ln_m + e * LN2_LO)
+}
+
+#[inline]
+fn log2_f64(x: f64) -> f64 {
+    if let Some(y) = ln_special(x) {
+        return y;
+    }
+    let (e, ln_m) = ln_parts(x);
+    e + ln_m * LOG2_E
+}
+
+/// `ln(1 + d)`, without cancellation for small `d`.
+#[inline]
+fn ln_1p_f64(d: f64) -> f64 {
+    if d.abs() < 0.25 {
+        let s = d / (2.0 + d);
+        s * horner(&ATANH, s * s)
+    } else {
+        ln_f64(1.0 + d)
+    }
+}
+
+/// Reduces `x` to `r` in [-π/4, π/4] and a quadrant `q`, with `x = q π/2 + r` modulo 2π.
+///
+/// The product of the mantissa of `x` with 2/π is computed exactly in integers, skipping the
+/// bits of 2/π that only contribute multiples of 2π, as in the method of Payne and Hanek.
+#[inline]
+fn reduce(x: f32) -> (f64, u32) {
+    if !x.is_finite() {
+        return (f64::NAN, 0);
+    }
+    if x.abs() <= FRAC_PI_4 as f32 {
+        return (x as f64, 0);
+    }
+    // |x| = m 2^e, with an integer `m`.
+    let bits = x.abs().to_bits();
+    let m = ((bits & 0x007f_ffff) | 0x0080_0000) as u128;
+    let e = (bits >> 23) as i32 - 150;
+    // Bits of 2/π up to `skip` make multiples of 4 in x 2/π.
+    let skip = (e - 2).max(0) as u32;
+    let (i, o) = ((skip / 32) as usize, skip % 32);
+    let words = TWO_OVER_PI[i..i + 4]
+        .iter()
+        .fold(0_u128, |acc, &w| (acc << 32) | w as u128);
+    let next = ((TWO_OVER_PI[i + 4] as u64) << o) >> 32;
+    let window = ((words << o) | next as u128) >> (128 - WINDOW);
+    // x 2/π modulo 4 is `product / 2^shift`.
+    let shift = (WINDOW as i32 + skip as i32 - e) as u32;
+    let product = m * window;
+    let mut q = (product >> shift) as u32 & 3;
+    let mut f = (product & ((1_u128 << shift) - 1)) as f64 / pow2i(shift as i64);
+    if f >= 0.5 {
+        f -= 1.0;
+        q += 1;
+    }
+    let r = f * FRAC_PI_2;
+    if x < 0.0 {
+        (-r, (4 - q) & 3)
+    } else {
+        (r, q & 3)
+    }
+}
+
+/// Sine of `r` in [-π/4, π/4].
+#[inline]
+fn sin_kernel(r: f64) -> f64 {
+    let z = r * r;
+    r + r * z * horner(&SIN, z)
+}
+
+/// Cosine of `r` in [-π/4, π/4].
+#[inline]
+fn cos_kernel(r: f64) -> f64 {
+    let z = r * r;
+    1.0 + z * horner(&COS, z)
+}
+
+/// `ln(erfcx(z) / t)` with `t = 2 / (2 + z)`, for non-negative `z`.
+#[inline]
+fn erfcx_ln_poly(t: f64) -> f64 {
+    // Clenshaw recurrence.
+    let u = 2.0 * t - 1.0;
+    let (mut b1, mut b2) = (0.0, 0.0);
+    for &c in ERFCX[1..].iter().rev() {
+        let b0 = c + 2.0 * u * b1 - b2;
+        b2 = b1;
+        b1 = b0;
+    }
+    ERFCX[0] + u * b1 - b2
+}
+
+/// Complementary error function of a non-negative `z`.
+#[inline]
+fn erfc_pos(z: f64) -> f64 {
+    let t = 2.0 / (2.0 + z);
+    t * exp_f64(erfcx_ln_poly(t) - z * z)
+}
+
+#[inline]
+fn erf_f64(x: f64) -> f64 {
+    let z = x.abs();
+    if z < 1.0 {
+        x * horner(&ERF, x * x)
+    } else {
+        (1.0 - erfc_pos(z)).copysign(x)
+    }
+}
+
+#[inline]
+fn erfc_f64(x: f64) -> f64 {
+    if x < 0.0 {
+        2.0 - erfc_pos(-x)
+    } else {
+        erfc_pos(x)
+    }
+}
+
+/// Lambert W function in double precision, by Halley iterations from the initial guesses of
+/// [`crate::reference::lambertw`].
+fn lambertw_f64(x: f64) -> f64 {
+    if x == 0.0 || x.is_nan() {
+        return x;
+    }
+    let mut w = if x < -0.3 {
+        // Series around the branch point.
+        let p = (2.0 * (std::f64::consts::E * x + 1.0)).max(0.0).sqrt();
+        -1.0 + p - p * p / 3.0 + 11.0 / 72.0 * p * p * p
+    } else if x < std::f64::consts::E {
+        0.75 * ln_1p_f64(x)
+    } else {
+        let l1 = ln_f64(x);
+        let l2 = ln_f64(l1);
+        l1 - l2 + l2 / l1
+    };
+    for _ in 0..32 {
+        let ew = exp_f64(w);
+        let f = w * ew - x;
+        let wp1 = w + 1.0;
+        if wp1.abs() < 1e-300 {
+            break;
+        }
+        let step = f / (ew * wp1 - (w + 2.0) * f / (2.0 * wp1));
+        w -= step;
+        if step.abs() <= 1e-15 * w.abs() {
+            break;
+        }
+    }
+    w
+}
+
+/// `x^-s`, for positive `x`.
+#[inline]
+fn pow_neg(x: f64, s: f64) -> f64 {
+    exp_f64(-s * ln_f64(x))
+}
+
+/// Hurwitz zeta function in double precision, by Euler–Maclaurin summation.
+fn hurwitz_zeta_f64(s: f64, q: f64) -> f64 {
+    const N: u32 = 10;
+
+    let mut sum: f64 = (0..N).map(|k| pow_neg(q + k as f64, s)).sum();
+    let a = q + N as f64;
+    let a_s = pow_neg(a, s);
+    sum += a * a_s / (s - 1.0) + 0.5 * a_s;
+    // s (s + 1) ... (s + 2j - 2) a^(-s - 2j + 1)
+    let mut factor = s * a_s / a;
+    for (j, b) in BERNOULLI.iter().enumerate() {
+        sum += b * factor;
+        let m = (2 * j + 1) as f64;
+        factor *= (s + m) * (s + m + 1.0) / (a * a);
+    }
+    sum
+}
+
+/// Exponential integral E1 in double precision, for positive `x`.
+fn exp_int_e1_f64(x: f64) -> f64 {
+    if x < 1.0 {
+        return -exp_int_ei_f64(-x);
+    }
+    // Continued fraction, evaluated backwards.
+    let mut t = x;
+    for k in (1..48).rev() {
+        t = x + k as f64 / (1.0 + k as f64 / t);
+    }
+    exp_f64(-x) / t
+}
+
+/// Exponential integral Ei in double precision.
+fn exp_int_ei_f64(x: f64) -> f64 {
+    if x < -1.0 {
+        return -exp_int_e1_f64(-x);
+    }
+    if x > 40.0 {
+        // Asymptotic expansion, up to its smallest term.
+        let mut term = 1.0;
+        let mut sum = 0.0;
+        for k in 1..=x as u32 {
+            sum += term;
+            term *= k as f64 / x;
+        }
+        return exp_f64(x) / x * sum;
+    }
+    let mut term = 1.0;
+    let mut sum = 0.0;
+    for k in 1..200 {
+        term *= x / k as f64;
+        let add = term / k as f64;
+        sum += add;
+        if add.abs() <= 1e-17 * sum.abs() {
+            break;
+        }
+    }
+    EULER + ln_f64(x.abs()) + sum
+}
+
+/// Power series of `I_n(a) (a/2)^-n n!` for `n` of zero or one, without its leading one.
+#[inline]
+fn bessel_series_tail(n: u32, a: f64) -> f64 {
+    let q = 0.25 * a * a;
+    let mut term = 1.0;
+    let mut sum = 0.0;
+    for k in 1..100 {
+        term *= q / (k * (k + n)) as f64;
+        sum += term;
+        if term <= 1e-17 * sum {
+            break;
+        }
+    }
+    sum
+}
+
+/// Asymptotic expansion of `I_n(a) exp(-a) sqrt(2 π a)`, for `a` above `BESSEL_SPLIT`.
+#[inline]
+fn bessel_asymptotic(n: u32, a: f64) -> f64 {
+    let mu = (4 * n * n) as f64;
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    for k in 1..30 {
+        let odd = (2 * k - 1) as f64;
+        term *= -(mu - odd * odd) / (8.0 * k as f64 * a);
+        sum += term;
+    }
+    sum
+}
+
+/// Modified Bessel function of the first kind of order `n`, zero or one, of a non-negative `a`.
+#[inline]
+fn bessel_i_pos(n: u32, a: f64) -> f64 {
+    if a <= BESSEL_SPLIT {
+        let lead = if n == 0 { 1.0 } else { 0.5 * a };
+        lead * (1.0 + bessel_series_tail(n, a))
+    } else {
+        exp_f64(a) * bessel_asymptotic(n, a) / (2.0 * PI * a).sqrt()
+    }
+}
+
+/// Base 2 logarithm.
+#[inline]
+pub fn log2(x: f32) -> f32 {
+    log2_f64(x as f64) as f32
+}
+
+/// Natural logarithm.
+#[inline]
+pub fn ln(x: f32) -> f32 {
+    ln_f64(x as f64) as f32
+}
+
+/// Raises 2 to a floating point power.
+#[inline]
+pub fn pow2(p: f32) -> f32 {
+    exp2_f64(p as f64) as f32
+}
+
+/// Raises a number to a floating point power.
+///
+/// Only works for non-negative `x`, with `x^0 = 1`.
+#[inline]
+pub fn pow(x: f32, p: f32) -> f32 {
+    if p == 0.0 {
+        return 1.0;
+    }
+    exp2_f64(p as f64 * log2_f64(x as f64)) as f32
+}
+
+/// Exponential function.
+#[inline]
+pub fn exp(p: f32) -> f32 {
+    exp_f64(p as f64) as f32
+}
+
+/// Sigmoid function.
+#[inline]
+pub fn sigmoid(x: f32) -> f32 {
+    (1.0 / (1.0 + exp_f64(-x as f64))) as f32
+}
+
+/// Inverse square root.
+#[inline]
+pub fn rsqrt(x: f32) -> f32 {
+    (1.0 / (x as f64).sqrt()) as f32
+}
+
+/// Natural logarithm of the Gamma function.
+///
+/// Only works for positive values.
+/// Shifts the argument above 8 with the recurrence and uses Stirling's series there, or the
+/// power series around the zeros at 1 and 2.
+#[inline]
+pub fn ln_gamma(x: f32) -> f32 {
+    let mut y = x as f64;
+    if (y - 1.0).abs() < 0.25 {
+        let z = y - 1.0;
+        return (z * (z * horner(&LN_GAMMA_1P, z) - EULER)) as f32;
+    }
+    if (y - 2.0).abs() < 0.25 {
+        let z = y - 2.0;
+        return (ln_1p_f64(z) + z * (z * horner(&LN_GAMMA_1P, z) - EULER)) as f32;
+    }
+    let mut product = 1.0;
+    while y < 8.0 {
+        product *= y;
+        y += 1.0;
+    }
+    let r = 1.0 / y;
+    let stirling =
+        (y - 0.5) * ln_f64(y) - y + 0.5 * ln_f64(2.0 * PI) + r * horner(&STIRLING, r * r);
+    (stirling - ln_f64(product)) as f32
+}
+
+/// Digamma function.
+///
+/// Only works for positive values.
+/// Shifts the argument above 10 with the recurrence and uses the asymptotic series there, or
+/// the Taylor series around the positive zero.
+#[inline]
+pub fn digamma(x: f32) -> f32 {
+    let mut y = x as f64;
+    let d = (y - DIGAMMA_ROOT) - DIGAMMA_ROOT_LO;
+    if d.abs() < 0.125 {
+        return (d * horner(&DIGAMMA_TAYLOR, d)) as f32;
+    }
+    let mut shift = 0.0;
+    while y < 10.0 {
+        shift -= 1.0 / y;
+        y += 1.0;
+    }
+    let r = 1.0 / (y * y);
+    (shift + ln_f64(y) - 0.5 / y - r * horner(&DIGAMMA, r)) as f32
+}
+
+/// Complementary error function.
+#[inline]
+pub fn erfc(x: f32) -> f32 {
+    erfc_f64(x as f64) as f32
+}
+
+/// Error function.
+#[inline]
+pub fn erf(x: f32) -> f32 {
+    erf_f64(x as f64) as f32
+}
+
+/// Inverse error function.
+///
+/// Refines the approximation of Giles with Newton iterations, solving for `erfc` instead of
+/// `erf` near ±1.
+#[inline]
+pub fn erf_inv(x: f32) -> f32 {
+    let x = x as f64;
+    let a = x.abs();
+    if a == 1.0 {
+        return f32::INFINITY.copysign(x as f32);
+    }
+    if a.is_nan() || a > 1.0 {
+        return f32::NAN;
+    }
+    let mut w = -ln_f64((1.0 - a) * (1.0 + a));
+    let mut p;
+    if w < 5.0 {
+        w -= 2.5;
+        p = 2.81022636e-08;
+        p = 3.43273939e-07 + p * w;
+        p = -3.5233877e-06 + p * w;
+        p = -4.39150654e-06 + p * w;
+        p = 0.00021858087 + p * w;
+        p = -0.00125372503 + p * w;
+        p = -0.00417768164 + p * w;
+        p = 0.246640727 + p * w;
+        p = 1.50140941 + p * w;
+    } else {
+        w = w.sqrt() - 3.0;
+        p = -0.000200214257;
+        p = 0.000100950558 + p * w;
+        p = 0.00134934322 + p * w;
+        p = -0.00367342844 + p * w;
+        p = 0.00573950773 + p * w;
+        p = -0.0076224613 + p * w;
+        p = 0.00943887047 + p * w;
+        p = 1.00167406 + p * w;
+        p = 2.83297682 + p * w;
+    }
+    let mut y = p * a;
+    for _ in 0..2 {
+        let slope = FRAC_2_SQRT_PI * exp_f64(-y * y);
+        y -= if a < 0.5 {
+            (erf_f64(y) - a) / slope
+        } else {
+            (1.0 - a - erfc_pos(y)) / slope
+        };
+    }
+    y.copysign(x) as f32
+}
+
+/// Hyperbolic sine function.
+#[inline]
+pub fn sinh(p: f32) -> f32 {
+    let a = (p as f64).abs();
+    let s = if a > 90.0 {
+        f64::INFINITY
+    } else {
+        let em = expm1_f64(a);
+        0.5 * (em + em / (em + 1.0))
+    };
+    s.copysign(p as f64) as f32
+}
+
+/// Hyperbolic cosine function.
+#[inline]
+pub fn cosh(p: f32) -> f32 {
+    let e = exp_f64((p as f64).abs());
+    (0.5 * (e + 1.0 / e)) as f32
+}
+
+/// Hyperbolic tangent function.
+#[inline]
+pub fn tanh(p: f32) -> f32 {
+    let a = (p as f64).abs();
+    let t = if a > 20.0 {
+        1.0
+    } else {
+        let em = expm1_f64(2.0 * a);
+        em / (em + 2.0)
+    };
+    t.copysign(p as f64) as f32
+}
+
+/// Lambert W function.
+///
+/// Only works for `x ≥ -1/e`.
+#[inline]
+pub fn lambertw(x: f32) -> f32 {
+    lambertw_f64(x as f64) as f32
+}
+
+/// Exponent of Lambert W function.
+///
+/// Solves `w + ln w = x` with Newton iterations for large values, so that it does not
+/// overflow.
+#[inline]
+pub fn lambertwexpx(x: f32) -> f32 {
+    let x = x as f64;
+    if x < 1.0 {
+        return lambertw_f64(exp_f64(x)) as f32;
+    }
+    let mut w = x - ln_f64(x);
+    for _ in 0..32 {
+        let step = (w + ln_f64(w) - x) / (1.0 + 1.0 / w);
+        w -= step;
+        if step.abs() <= 1e-15 * w {
+            break;
+        }
+    }
+    w as f32
+}
+
+/// Sine in radians.
+///
+/// Accurate for all finite values, like [`sinfull`].
+#[inline]
+pub fn sin(x: f32) -> f32 {
+    sinfull(x)
+}
+
+/// Sine in radians.
+#[inline]
+pub fn sinfull(x: f32) -> f32 {
+    let (r, q) = reduce(x);
+    let y = match q {
+        0 => sin_kernel(r),
+        1 => cos_kernel(r),
+        2 => -sin_kernel(r),
+        _ => -cos_kernel(r),
+    };
+    y as f32
+}
+
+/// Cosine in radians.
+///
+/// Accurate for all finite values, like [`cosfull`].
+#[inline]
+pub fn cos(x: f32) -> f32 {
+    cosfull(x)
+}
+
+/// Cosine in radians.
+#[inline]
+pub fn cosfull(x: f32) -> f32 {
+    let (r, q) = reduce(x);
+    let y = match q {
+        0 => cos_kernel(r),
+        1 => -sin_kernel(r),
+        2 => -cos_kernel(r),
+        _ => sin_kernel(r),
+    };
+    y as f32
+}
+
+/// Tangent in radians.
+///
+/// Accurate for all finite values, like [`tanfull`].
+#[inline]
+pub fn tan(x: f32) -> f32 {
+    tanfull(x)
+}
+
+/// Tangent in radians.
+#[inline]
+pub fn tanfull(x: f32) -> f32 {
+    let (r, q) = reduce(x);
+    let (s, c) = (sin_kernel(r), cos_kernel(r));
+    let y = if q % 2 == 0 { s / c } else { -c / s };
+    y as f32
+}
+
+/// Scaled complementary error function `exp(x²) * erfc(x)`.
+///
+/// Unlike [`erfc`], stays accurate for large positive values.
+#[inline]
+pub fn erfcx(x: f32) -> f32 {
+    let x = x as f64;
+    let z = x.abs();
+    let t = 2.0 / (2.0 + z);
+    let r = t * exp_f64(erfcx_ln_poly(t));
+    if x < 0.0 {
+        (2.0 * exp_f64(x * x) - r) as f32
+    } else {
+        r as f32
+    }
+}
+
+/// Natural logarithm of the complementary error function.
+///
+/// Unlike `ln(erfc(x))`, stays finite for large positive values.
+#[inline]
+pub fn ln_erfc(x: f32) -> f32 {
+    let x = x as f64;
+    if x < 1.0 {
+        ln_1p_f64(-erf_f64(x)) as f32
+    } else {
+        let t = 2.0 / (2.0 + x);
+        (ln_f64(t) + erfcx_ln_poly(t) - x * x) as f32
+    }
+}
+
+/// Riemann zeta function.
+///
+/// Only works for `s > 1`.
+#[inline]
+pub fn zeta(s: f32) -> f32 {
+    hurwitz_zeta_f64(s as f64, 1.0) as f32
+}
+
+/// Hurwitz zeta function `ζ(s, q) = Σ (q + k)^(-s)` over `k ≥ 0`.
+///
+/// Only works for `s > 1` and positive `q`.
+#[inline]
+pub fn hurwitz_zeta(s: f32, q: f32) -> f32 {
+    hurwitz_zeta_f64(s as f64, q as f64) as f32
+}
+
+/// Exponential integral E1.
+///
+/// Only works for positive values.
+#[inline]
+pub fn exp_int_e1(x: f32) -> f32 {
+    exp_int_e1_f64(x as f64) as f32
+}
+
+/// Exponential integral Ei.
+///
+/// Works for non-zero values.
+#[inline]
+pub fn exp_int_ei(x: f32) -> f32 {
+    exp_int_ei_f64(x as f64) as f32
+}
+
+/// Logarithmic integral li.
+///
+/// Works for positive values other than one.
+#[inline]
+pub fn log_int(x: f32) -> f32 {
+    exp_int_ei_f64(ln_f64(x as f64)) as f32
+}
+
+/// Modified Bessel function of the first kind of order zero.
+#[inline]
+pub fn bessel_i0(x: f32) -> f32 {
+    bessel_i_pos(0, (x as f64).abs()) as f32
+}
+
+/// Modified Bessel function of the first kind of order one.
+#[inline]
+pub fn bessel_i1(x: f32) -> f32 {
+    bessel_i_pos(1, (x as f64).abs()).copysign(x as f64) as f32
+}
+
+/// Natural logarithm of the modified Bessel function of the first kind of order zero.
+///
+/// Does not overflow for large arguments.
+#[inline]
+pub fn ln_bessel_i0(x: f32) -> f32 {
+    let a = (x as f64).abs();
+    if a <= BESSEL_SPLIT {
+        ln_1p_f64(bessel_series_tail(0, a)) as f32
+    } else {
+        (a + ln_f64(bessel_asymptotic(0, a)) - 0.5 * ln_f64(2.0 * PI * a)) as f32
+    }
+}
+
+/// Ratio of modified Bessel functions of the first kind I1(x) / I0(x).
+///
+/// This is the mean resultant length of the von Mises distribution with concentration `x`.
+#[inline]
+pub fn bessel_i1_over_i0(x: f32) -> f32 {
+    let a = (x as f64).abs();
+    let r = if a <= BESSEL_SPLIT {
+        0.5 * a * (1.0 + bessel_series_tail(1, a)) / (1.0 + bessel_series_tail(0, a))
+    } else {
+        bessel_asymptotic(1, a) / bessel_asymptotic(0, a)
+    };
+    r.copysign(x as f64) as f32
+}
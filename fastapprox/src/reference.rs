@@ -0,0 +1,405 @@
+//! High-precision references for the approximated functions, evaluated in double precision.
+//!
+//! They are accurate to well below single precision over the domains listed in
+//! [`crate::registry`], which is all they are meant for, and are far too slow for anything but
+//! checking the approximations.
+
+use std::f64::consts::{E, FRAC_2_SQRT_PI, PI};
+use std::sync::OnceLock;
+
+/// Euler–Mascheroni constant.
+pub const EULER: f64 = 0.5772156649015329;
+
+/// Positive zero of the digamma function, rounded to double precision.
+pub const DIGAMMA_ROOT: f64 = 1.4616321449683622;
+/// Difference between the positive zero of the digamma function and [`DIGAMMA_ROOT`].
+pub const DIGAMMA_ROOT_LO: f64 = 9.549995429965697e-17;
+
+/// Arguments above which `erfc` switches from `1 - erf` to its continued fraction.
+const ERFC_SPLIT: f64 = 2.5;
+
+/// Sigmoid function.
+pub fn sigmoid(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+/// Inverse square root.
+pub fn rsqrt(x: f64) -> f64 {
+    1.0 / x.sqrt()
+}
+
+/// Natural logarithm of the Gamma function for positive values, using the Lanczos
+/// approximation with g = 7.
+pub fn ln_gamma(x: f64) -> f64 {
+    const G: f64 = 7.0;
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+
+    if x < 0.5 {
+        return ln_gamma(x + 1.0) - x.ln();
+    }
+    // The Lanczos sum only has an absolute accuracy, so the zeros at 1 and 2 take a series.
+    if (x - 1.0).abs() < 0.25 {
+        return ln_gamma_1p(x - 1.0);
+    }
+    if (x - 2.0).abs() < 0.25 {
+        return (x - 2.0).ln_1p() + ln_gamma_1p(x - 2.0);
+    }
+    let x = x - 1.0;
+    let t = x + G + 0.5;
+    let sum = COEFFICIENTS[1..]
+        .iter()
+        .enumerate()
+        .fold(COEFFICIENTS[0], |s, (i, &c)| s + c / (x + (i + 1) as f64));
+    0.5 * (2.0 * PI).ln() + (x + 0.5) * t.ln() - t + sum.ln()
+}
+
+/// Number of terms of the series around the zeros of `ln Γ` and the digamma function.
+const SERIES_TERMS: usize = 38;
+
+/// `ζ(k, q)` from k = 2, computed on first use.
+fn zeta_terms(q: f64, cache: &'static OnceLock<[f64; SERIES_TERMS]>) -> &'static [f64] {
+    cache.get_or_init(|| std::array::from_fn(|k| hurwitz_zeta((k + 2) as f64, q)))
+}
+
+/// `ln Γ(1 + z)` by its power series, for `|z| ≤ 1/4`.
+fn ln_gamma_1p(z: f64) -> f64 {
+    static ZETA: OnceLock<[f64; SERIES_TERMS]> = OnceLock::new();
+    let mut sum = -EULER * z;
+    let mut power = -z;
+    for (k, zeta) in (2..).zip(zeta_terms(1.0, &ZETA)) {
+        power *= -z;
+        sum += zeta * power / k as f64;
+    }
+    sum
+}
+
+/// Digamma function for positive values, using the recurrence and the asymptotic series.
+///
+/// Around its positive zero, it uses the Taylor series there instead.
+pub fn digamma(x: f64) -> f64 {
+    static ZETA: OnceLock<[f64; SERIES_TERMS]> = OnceLock::new();
+    let d = (x - DIGAMMA_ROOT) - DIGAMMA_ROOT_LO;
+    if d.abs() < 0.25 {
+        // The k-th derivative at the zero is (-1)^(k + 1) k! ζ(k + 1, DIGAMMA_ROOT).
+        let mut sum = 0.0;
+        let mut power = -1.0;
+        for zeta in zeta_terms(DIGAMMA_ROOT, &ZETA) {
+            power *= -d;
+            sum += zeta * power;
+        }
+        return sum;
+    }
+    let mut x = x;
+    let mut result = 0.0;
+    while x < 10.0 {
+        result -= 1.0 / x;
+        x += 1.0;
+    }
+    let r = 1.0 / (x * x);
+    let series = r
+        * (1.0 / 12.0
+            - r * (1.0 / 120.0 - r * (1.0 / 252.0 - r * (1.0 / 240.0 - r * (1.0 / 132.0)))));
+    result + x.ln() - 0.5 / x - series
+}
+
+/// Continued fraction of `exp(x²) * erfc(x)`, for `x ≥ ERFC_SPLIT`.
+fn erfcx_cf(x: f64) -> f64 {
+    let mut t = x;
+    for k in (1..200).rev() {
+        t = x + 0.5 * k as f64 / t;
+    }
+    1.0 / (PI.sqrt() * t)
+}
+
+/// Error function, using its power series for small values.
+pub fn erf(x: f64) -> f64 {
+    if x.abs() >= ERFC_SPLIT {
+        return x.signum() * (1.0 - erfc(x.abs()));
+    }
+    let x2 = x * x;
+    let mut term = x;
+    let mut sum = x;
+    for n in 1..200 {
+        term *= -x2 / n as f64;
+        let add = term / (2 * n + 1) as f64;
+        sum += add;
+        if add.abs() < 1e-17 * sum.abs() {
+            break;
+        }
+    }
+    FRAC_2_SQRT_PI * sum
+}
+
+/// Complementary error function.
+pub fn erfc(x: f64) -> f64 {
+    if x < 0.0 {
+        2.0 - erfc(-x)
+    } else if x < ERFC_SPLIT {
+        1.0 - erf(x)
+    } else {
+        (-x * x).exp() * erfcx_cf(x)
+    }
+}
+
+/// Scaled complementary error function `exp(x²) * erfc(x)`.
+pub fn erfcx(x: f64) -> f64 {
+    if x < ERFC_SPLIT {
+        (x * x).exp() * erfc(x)
+    } else {
+        erfcx_cf(x)
+    }
+}
+
+/// Natural logarithm of the complementary error function.
+pub fn ln_erfc(x: f64) -> f64 {
+    if x.abs() < 0.5 {
+        (-erf(x)).ln_1p()
+    } else if x < ERFC_SPLIT {
+        erfc(x).ln()
+    } else {
+        erfcx_cf(x).ln() - x * x
+    }
+}
+
+/// Inverse error function on (-1, 1).
+///
+/// Refines the single-precision approximation of Giles with Newton iterations, solving for
+/// `erfc` instead of `erf` near ±1 to avoid cancellation.
+pub fn erf_inv(x: f64) -> f64 {
+    let mut w = -((1.0 - x) * (1.0 + x)).ln();
+    let mut y = if w < 5.0 {
+        w -= 2.5;
+        let mut p = 2.81022636e-08;
+        p = 3.43273939e-07 + p * w;
+        p = -3.5233877e-06 + p * w;
+        p = -4.39150654e-06 + p * w;
+        p = 0.00021858087 + p * w;
+        p = -0.00125372503 + p * w;
+        p = -0.00417768164 + p * w;
+        p = 0.246640727 + p * w;
+        p = 1.50140941 + p * w;
+        p * x
+    } else {
+        w = w.sqrt() - 3.0;
+        let mut p = -0.000200214257;
+        p = 0.000100950558 + p * w;
+        p = 0.00134934322 + p * w;
+        p = -0.00367342844 + p * w;
+        p = 0.00573950773 + p * w;
+        p = -0.0076224613 + p * w;
+        p = 0.00943887047 + p * w;
+        p = 1.00167406 + p * w;
+        p = 2.83297682 + p * w;
+        p * x
+    };
+    let (a, c) = (x.abs(), 1.0 - x.abs());
+    y = y.abs();
+    for _ in 0..4 {
+        let slope = FRAC_2_SQRT_PI * (-y * y).exp();
+        if slope == 0.0 {
+            break;
+        }
+        y -= if a < 0.5 {
+            (erf(y) - a) / slope
+        } else {
+            (c - erfc(y)) / slope
+        };
+    }
+    x.signum() * y
+}
+
+/// Principal branch of the Lambert W function for `x ≥ -1/e`, using Halley iterations.
+pub fn lambertw(x: f64) -> f64 {
+    if x == 0.0 {
+        return 0.0;
+    }
+    let mut w = if x < -0.3 {
+        // Series around the branch point.
+        let p = (2.0 * (E * x + 1.0)).max(0.0).sqrt();
+        -1.0 + p - p * p / 3.0 + 11.0 / 72.0 * p * p * p
+    } else if x < E {
+        x.ln_1p() * 0.75
+    } else {
+        let l1 = x.ln();
+        let l2 = l1.ln();
+        l1 - l2 + l2 / l1
+    };
+    for _ in 0..100 {
+        let ew = w.exp();
+        let f = w * ew - x;
+        let wp1 = w + 1.0;
+        if wp1.abs() < 1e-300 {
+            break;
+        }
+        let step = f / (ew * wp1 - (w + 2.0) * f / (2.0 * wp1));
+        w -= step;
+        if step.abs() <= 1e-15 * w.abs() {
+            break;
+        }
+    }
+    w
+}
+
+/// Lambert W function of `exp(x)`, solving `w + ln w = x` directly for large values.
+pub fn lambertwexpx(x: f64) -> f64 {
+    if x < 1.0 {
+        return lambertw(x.exp());
+    }
+    let mut w = x - x.ln();
+    for _ in 0..100 {
+        let step = (w + w.ln() - x) / (1.0 + 1.0 / w);
+        w -= step;
+        if step.abs() <= 1e-15 * w {
+            break;
+        }
+    }
+    w
+}
+
+/// Hurwitz zeta function by Euler–Maclaurin summation with plenty of terms.
+pub fn hurwitz_zeta(s: f64, q: f64) -> f64 {
+    // B_2j / (2j)!
+    const BERNOULLI: &[f64] = &[
+        1.0 / 12.0,
+        -1.0 / 720.0,
+        1.0 / 30240.0,
+        -1.0 / 1209600.0,
+        1.0 / 47900160.0,
+        -691.0 / 1307674368000.0,
+    ];
+    const N: i32 = 30;
+
+    let mut sum: f64 = (0..N).map(|k| (q + k as f64).powf(-s)).sum();
+    let a = q + N as f64;
+    sum += a.powf(1.0 - s) / (s - 1.0) + 0.5 * a.powf(-s);
+    // s (s + 1) ... (s + 2j - 2) a^(-s - 2j + 1)
+    let mut factor = s * a.powf(-s - 1.0);
+    for (j, b) in BERNOULLI.iter().enumerate() {
+        sum += b * factor;
+        let m = (2 * j + 1) as f64;
+        factor *= (s + m) * (s + m + 1.0) / (a * a);
+    }
+    sum
+}
+
+/// Riemann zeta function for `s > 1`.
+pub fn zeta(s: f64) -> f64 {
+    hurwitz_zeta(s, 1.0)
+}
+
+/// Exponential integral Ei by its power series, or its asymptotic expansion for large values.
+///
+/// Negative values below minus one are delegated to [`exp_int_e1`].
+pub fn exp_int_ei(x: f64) -> f64 {
+    if x < -1.0 {
+        return -exp_int_e1(-x);
+    }
+    if x > 50.0 {
+        let mut term = 1.0;
+        let mut sum = 0.0;
+        for k in 1..40 {
+            sum += term;
+            term *= k as f64 / x;
+        }
+        return x.exp() / x * sum;
+    }
+    let mut term = 1.0;
+    let mut sum = 0.0;
+    for k in 1..500 {
+        term *= x / k as f64;
+        let add = term / k as f64;
+        sum += add;
+        if add.abs() < 1e-17 * sum.abs() {
+            break;
+        }
+    }
+    EULER + x.abs().ln() + sum
+}
+
+/// Exponential integral E1 by its continued fraction, or its power series for small values.
+pub fn exp_int_e1(x: f64) -> f64 {
+    if x < 1.0 {
+        return -exp_int_ei(-x);
+    }
+    let mut t = x;
+    for k in (1..200).rev() {
+        t = x + k as f64 / (1.0 + k as f64 / t);
+    }
+    (-x).exp() / t
+}
+
+/// Logarithmic integral li.
+pub fn log_int(x: f64) -> f64 {
+    exp_int_ei(x.ln())
+}
+
+/// Modified Bessel function of the first kind of order `n` divided by `exp(|x|)`.
+///
+/// Uses the power series up to 50 and the asymptotic expansion beyond.
+fn bessel_i_scaled(n: i32, x: f64) -> f64 {
+    let a = x.abs();
+    let sign = if x < 0.0 && n % 2 == 1 { -1.0 } else { 1.0 };
+    if a <= 50.0 {
+        let q = 0.25 * x * x;
+        let mut term = (0.5 * a).powi(n) / (1..=n).product::<i32>() as f64;
+        let mut sum = term;
+        for k in 1..500 {
+            term *= q / (k * (k + n)) as f64;
+            sum += term;
+            if term < 1e-17 * sum {
+                break;
+            }
+        }
+        return sign * sum * (-a).exp();
+    }
+    let mu = (4 * n * n) as f64;
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    for k in 1..30 {
+        let odd = (2 * k - 1) as f64;
+        term *= -(mu - odd * odd) / (8.0 * k as f64 * a);
+        sum += term;
+    }
+    sign * sum / (2.0 * PI * a).sqrt()
+}
+
+/// Modified Bessel function of the first kind of order `n`.
+pub fn bessel_i(n: i32, x: f64) -> f64 {
+    bessel_i_scaled(n, x) * x.abs().exp()
+}
+
+/// Natural logarithm of the modified Bessel function of the first kind of order zero.
+///
+/// Sums the power series without its leading one for small values, to keep the relative
+/// accuracy near zero.
+pub fn ln_bessel_i0(x: f64) -> f64 {
+    if x.abs() < 1.0 {
+        let q = 0.25 * x * x;
+        let mut term = 1.0;
+        let mut sum = 0.0;
+        for k in 1..50 {
+            term *= q / (k * k) as f64;
+            sum += term;
+            if term < 1e-17 * sum {
+                break;
+            }
+        }
+        return sum.ln_1p();
+    }
+    bessel_i_scaled(0, x).ln() + x.abs()
+}
+
+/// Ratio of modified Bessel functions of the first kind I1(x) / I0(x).
+pub fn bessel_i1_over_i0(x: f64) -> f64 {
+    bessel_i_scaled(1, x) / bessel_i_scaled(0, x)
+}
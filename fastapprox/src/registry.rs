@@ -0,0 +1,320 @@
+//! Metadata of every approximation in [`crate::fast`], [`crate::faster`] and
+//! [`crate::precise`].
+//!
+//! Each [`FunctionInfo`] in [`ALL`] names a function and its tier, and holds a pointer to it,
+//! a double precision reference from [`crate::reference`], the interval of inputs it is meant
+//! for and a bound on its error there. Test suites, benchmarks and documentation can iterate
+//! over the registry instead of maintaining their own lists.
+//!
+//! Functions of two arguments, `pow` and `hurwitz_zeta`, are in [`BINARY`] instead. Their
+//! [`BinaryInfo`] gives the interval of the first argument and a few values of the second,
+//! and the bound holds over the interval with the second argument fixed at any of them.
+//!
+//! # Examples
+//!
+//! ```
+//! use fastapprox::registry::{self, Tier};
+//!
+//! let info = registry::get(Tier::Faster, "lambertwexpx").unwrap();
+//! let (lo, hi) = info.domain;
+//! assert!(lo < 0.0 && hi >= 1e9);
+//!
+//! let x = 3.0_f32;
+//! assert!(info.error.holds((info.approx)(x), (info.reference)(x as f64)));
+//! ```
+//!
+//! [`ALL`]: crate::registry::ALL
+//! [`FunctionInfo`]: crate::registry::FunctionInfo
+//! [`BINARY`]: crate::registry::BINARY
+//! [`BinaryInfo`]: crate::registry::BinaryInfo
+
+use std::f32::consts::PI;
+use std::fmt;
+
+use crate::reference;
+use crate::{fast, faster, precise};
+
+/// Speed-accuracy level of an approximation, the module it lives in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Tier {
+    /// [`crate::fast`].
+    Fast,
+    /// [`crate::faster`].
+    Faster,
+    /// [`crate::precise`].
+    Precise,
+}
+
+impl Tier {
+    /// Name of the module, `fast`, `faster` or `precise`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Tier::Fast => "fast",
+            Tier::Faster => "faster",
+            Tier::Precise => "precise",
+        }
+    }
+}
+
+impl fmt::Display for Tier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Bound `abs + rel · |y|` on the absolute error of an approximation of `y`.
+///
+/// Mixing both terms covers functions with zeros, where no relative bound holds, as well as
+/// functions growing without bound, where no absolute bound holds.
+/// The bounds were measured by sweeping the domain against the reference and rounded up
+/// with some margin. They only apply where the reference is finite in single precision.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ErrorBound {
+    /// Absolute term.
+    pub abs: f64,
+    /// Relative term.
+    pub rel: f64,
+}
+
+impl ErrorBound {
+    /// No bound, for functions whose error is unbounded somewhere in their domain.
+    pub const UNBOUNDED: ErrorBound = ErrorBound {
+        abs: f64::INFINITY,
+        rel: 0.0,
+    };
+
+    /// Largest error allowed in an approximation of `exact`.
+    pub fn tolerance(&self, exact: f64) -> f64 {
+        self.abs + self.rel * exact.abs()
+    }
+
+    /// Whether `actual` approximates `exact` within the bound.
+    pub fn holds(&self, actual: f32, exact: f64) -> bool {
+        (actual as f64 - exact).abs() <= self.tolerance(exact)
+    }
+}
+
+/// Metadata of an approximation of a function of one argument.
+#[derive(Clone, Copy, Debug)]
+pub struct FunctionInfo {
+    /// Name of the function, such as `ln`.
+    pub name: &'static str,
+    /// Tier of the approximation.
+    pub tier: Tier,
+    /// The approximation.
+    pub approx: fn(f32) -> f32,
+    /// Double precision reference.
+    pub reference: fn(f64) -> f64,
+    /// Closed interval of inputs the approximation is meant for.
+    pub domain: (f32, f32),
+    /// Bound on the error over the domain.
+    pub error: ErrorBound,
+}
+
+impl FunctionInfo {
+    /// Qualified name, such as `fast::ln`.
+    pub fn qualified_name(&self) -> String {
+        format!("{}::{}", self.tier, self.name)
+    }
+}
+
+/// Looks up the approximation of the function `name` in `tier`.
+pub fn get(tier: Tier, name: &str) -> Option<&'static FunctionInfo> {
+    ALL.iter().find(|f| f.tier == tier && f.name == name)
+}
+
+/// Metadata of an approximation of a function of two arguments.
+#[derive(Clone, Copy, Debug)]
+pub struct BinaryInfo {
+    /// Name of the function, such as `pow`.
+    pub name: &'static str,
+    /// Tier of the approximation.
+    pub tier: Tier,
+    /// The approximation.
+    pub approx: fn(f32, f32) -> f32,
+    /// Double precision reference.
+    pub reference: fn(f64, f64) -> f64,
+    /// Closed interval of the first argument.
+    pub domain: (f32, f32),
+    /// Values of the second argument at which the bound was measured.
+    pub fixed: &'static [f32],
+    /// Bound on the error over the domain, for each fixed second argument.
+    pub error: ErrorBound,
+}
+
+impl BinaryInfo {
+    /// Qualified name, such as `fast::pow`.
+    pub fn qualified_name(&self) -> String {
+        format!("{}::{}", self.tier, self.name)
+    }
+}
+
+/// Looks up the approximation of the function of two arguments `name` in `tier`.
+pub fn get_binary(tier: Tier, name: &str) -> Option<&'static BinaryInfo> {
+    BINARY.iter().find(|f| f.tier == tier && f.name == name)
+}
+
+macro_rules! registry {
+    ($(
+        $name:ident => $reference:expr, [$lo:expr, $hi:expr],
+        fast: $fast:expr, faster: $faster:expr;
+    )*) => {
+        /// All single-argument approximations, the `fast` one of each function followed by the
+        /// `faster` and `precise` ones.
+        pub const ALL: &[FunctionInfo] = &[
+            $(
+                FunctionInfo {
+                    name: stringify!($name),
+                    tier: Tier::Fast,
+                    approx: fast::$name,
+                    reference: $reference,
+                    domain: ($lo, $hi),
+                    error: $fast,
+                },
+                FunctionInfo {
+                    name: stringify!($name),
+                    tier: Tier::Faster,
+                    approx: faster::$name,
+                    reference: $reference,
+                    domain: ($lo, $hi),
+                    error: $faster,
+                },
+                FunctionInfo {
+                    name: stringify!($name),
+                    tier: Tier::Precise,
+                    approx: precise::$name,
+                    reference: $reference,
+                    domain: ($lo, $hi),
+                    error: PRECISE,
+                },
+            )*
+        ];
+    };
+}
+
+macro_rules! binary_registry {
+    ($(
+        $name:ident => $reference:expr, [$lo:expr, $hi:expr], at $fixed:expr,
+        fast: $fast:expr, faster: $faster:expr;
+    )*) => {
+        /// All approximations of functions of two arguments, in the same order as [`ALL`].
+        pub const BINARY: &[BinaryInfo] = &[
+            $(
+                BinaryInfo {
+                    name: stringify!($name),
+                    tier: Tier::Fast,
+                    approx: fast::$name,
+                    reference: $reference,
+                    domain: ($lo, $hi),
+                    fixed: $fixed,
+                    error: $fast,
+                },
+                BinaryInfo {
+                    name: stringify!($name),
+                    tier: Tier::Faster,
+                    approx: faster::$name,
+                    reference: $reference,
+                    domain: ($lo, $hi),
+                    fixed: $fixed,
+                    error: $faster,
+                },
+                BinaryInfo {
+                    name: stringify!($name),
+                    tier: Tier::Precise,
+                    approx: precise::$name,
+                    reference: $reference,
+                    domain: ($lo, $hi),
+                    fixed: $fixed,
+                    error: PRECISE,
+                },
+            )*
+        ];
+    };
+}
+
+const fn bound(abs: f64, rel: f64) -> ErrorBound {
+    ErrorBound { abs, rel }
+}
+
+/// Bound of every function in [`crate::precise`], a ULP and a half of relative error, or the
+/// smallest subnormal.
+const PRECISE: ErrorBound = bound(1.5e-45, 1.8e-7);
+
+/// Largest float below π.
+const PI_BELOW: f32 = f32::from_bits(PI.to_bits() - 1);
+
+registry! {
+    log2 => f64::log2, [f32::MIN_POSITIVE, f32::MAX],
+        fast: bound(3e-4, 0.0), faster: bound(0.15, 0.0);
+    ln => f64::ln, [f32::MIN_POSITIVE, f32::MAX],
+        fast: bound(1.5e-4, 3e-5), faster: bound(0.1, 0.0);
+    pow2 => f64::exp2, [-126.0, 127.0],
+        fast: bound(0.0, 1.5e-4), faster: bound(1e-38, 0.07);
+    exp => f64::exp, [-87.0, 88.0],
+        fast: bound(0.0, 1.5e-4), faster: bound(0.0, 0.07);
+    sigmoid => reference::sigmoid, [-87.0, 87.0],
+        fast: bound(3e-5, 0.0), faster: bound(0.015, 0.0);
+    rsqrt => reference::rsqrt, [f32::MIN_POSITIVE, f32::MAX],
+        fast: bound(0.0, 1e-5), faster: bound(1e-38, 3e-3);
+    ln_gamma => reference::ln_gamma, [1e-30, 1e30],
+        fast: bound(1e-3, 1.5e-4), faster: bound(0.3, 0.03);
+    digamma => reference::digamma, [1e-30, 1e10],
+        fast: bound(3e-4, 1e-4), faster: bound(0.1, 0.03);
+    erfc => reference::erfc, [-8.0, 9.0],
+        fast: bound(0.01, 0.0), faster: bound(0.1, 0.0);
+    erf => reference::erf, [-8.0, 8.0],
+        fast: bound(3e-3, 2e-3), faster: bound(0.03, 0.02);
+    erf_inv => reference::erf_inv, [-0.99999994, 0.99999994],
+        fast: bound(3e-6, 1.5), faster: bound(0.03, 1.5);
+    sinh => f64::sinh, [-88.0, 88.0],
+        fast: bound(3e-5, 1.5e-4), faster: bound(0.015, 0.07);
+    cosh => f64::cosh, [-88.0, 88.0],
+        fast: bound(0.0, 1.5e-4), faster: bound(0.0, 0.07);
+    tanh => f64::tanh, [-43.0, 43.0],
+        fast: bound(1e-4, 0.0), faster: bound(0.03, 0.0);
+    lambertw => reference::lambertw, [-0.36787942, 1e30],
+        fast: bound(0.1, 0.02), faster: bound(0.15, 0.02);
+    lambertwexpx => reference::lambertwexpx, [-80.0, 1e9],
+        fast: bound(1.5e-3, 7e-7), faster: bound(0.015, 0.02);
+    sin => f64::sin, [-PI_BELOW, PI_BELOW],
+        fast: bound(1e-4, 0.0), faster: bound(1.5e-3, 0.0);
+    sinfull => f64::sin, [-1000.0, 1000.0],
+        fast: bound(1.5e-4, 0.0), faster: bound(1.5e-3, 0.0);
+    cos => f64::cos, [-PI_BELOW, PI_BELOW],
+        fast: bound(1e-4, 0.0), faster: bound(0.01, 3e-3);
+    cosfull => f64::cos, [-1000.0, 1000.0],
+        fast: bound(1.5e-4, 0.0), faster: bound(1.5e-3, 0.0);
+    tan => f64::tan, [-1.57, 1.57],
+        fast: bound(1e-7, 3e-3), faster: bound(1e-7, 0.02);
+    tanfull => f64::tan, [-1000.0, 1000.0],
+        fast: ErrorBound::UNBOUNDED, faster: ErrorBound::UNBOUNDED;
+    erfcx => reference::erfcx, [-9.0, 1e30],
+        fast: bound(0.0, 1.5e-4), faster: bound(0.0, 0.07);
+    ln_erfc => reference::ln_erfc, [-8.0, 1e18],
+        fast: bound(1.5e-4, 1e-5), faster: bound(0.15, 2e-7);
+    zeta => reference::zeta, [1.0000001, 64.0],
+        fast: bound(0.0, 7e-5), faster: bound(0.0, 0.1);
+    exp_int_e1 => reference::exp_int_e1, [1e-30, 88.0],
+        fast: bound(1.5e-4, 1e-5), faster: bound(0.1, 0.0);
+    exp_int_ei => reference::exp_int_ei, [-88.0, 88.0],
+        fast: bound(1e-38, 4e-4), faster: bound(1e-38, 0.15);
+    log_int => reference::log_int, [2.0, 1e30],
+        fast: bound(0.0, 3e-4), faster: bound(0.0, 0.1);
+    bessel_i0 => |x| reference::bessel_i(0, x), [-88.0, 88.0],
+        fast: bound(0.0, 1.5e-4), faster: bound(0.0, 0.07);
+    bessel_i1 => |x| reference::bessel_i(1, x), [-88.0, 88.0],
+        fast: bound(1e-38, 1.5e-4), faster: bound(1e-38, 0.07);
+    ln_bessel_i0 => reference::ln_bessel_i0, [-1e4, 1e4],
+        fast: bound(1.5e-4, 3e-5), faster: bound(0.1, 0.0);
+    bessel_i1_over_i0 => reference::bessel_i1_over_i0, [-1e4, 1e4],
+        fast: bound(1.5e-6, 2e-7), faster: bound(0.015, 0.015);
+}
+
+// The domains keep the results normal and finite for every fixed argument. The error of
+// `faster::hurwitz_zeta` keeps growing with `s` beyond the domain.
+binary_registry! {
+    pow => f64::powf, [1e-12, 1e12], at &[-2.5, -0.5, 0.5, 1.5, 3.0],
+        fast: bound(0.0, 4e-4), faster: bound(0.0, 0.12);
+    hurwitz_zeta => reference::hurwitz_zeta, [1.0000001, 8.0], at &[0.5, 1.0, 2.1, 3.5, 100.0],
+        fast: bound(0.0, 8e-4), faster: bound(0.0, 0.3);
+}
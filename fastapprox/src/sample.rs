@@ -0,0 +1,152 @@
+//! Samplers draw from any [`RngCore`] and are exact up to the error of the underlying
+//! approximations, which shows up as a bias in the sampled distribution.
+//! Logarithms of uniform variates are clamped where the approximation error would otherwise
+//! produce a negative radius or rate.
+//!
+//! This module requires the `rand_core` feature.
+//!
+//! [`RngCore`]: rand_core::RngCore
+
+use rand_core::RngCore;
+
+/// Uniform variate in the open interval (0, 1), with 24 bits of randomness.
+#[inline]
+fn open01<R: RngCore + ?Sized>(rng: &mut R) -> f32 {
+    const SCALE: f32 = 1.0_f32 / (1u32 << 24) as f32;
+    ((rng.next_u32() >> 8) as f32 + 0.5_f32) * SCALE
+}
+
+macro_rules! sample_impl {
+    ($tier:ident) => {
+        use super::open01;
+        use crate::$tier::{cos, exp, ln, pow, sin};
+        use rand_core::RngCore;
+
+        const PI: f32 = std::f32::consts::PI;
+        const TWOPI: f32 = std::f32::consts::TAU;
+
+        /// Pair of independent standard normal variates, using the Box–Muller transform.
+        #[inline]
+        pub fn normal_pair<R: RngCore + ?Sized>(rng: &mut R) -> (f32, f32) {
+            let r = (-2.0_f32 * ln(open01(rng))).max(0.0_f32).sqrt();
+            let theta = TWOPI * open01(rng) - PI;
+            (r * cos(theta), r * sin(theta))
+        }
+
+        /// Standard normal variate, using the Marsaglia polar method.
+        #[inline]
+        pub fn normal<R: RngCore + ?Sized>(rng: &mut R) -> f32 {
+            loop {
+                let u = 2.0_f32 * open01(rng) - 1.0_f32;
+                let v = 2.0_f32 * open01(rng) - 1.0_f32;
+                let s = u * u + v * v;
+                if s < 1.0_f32 && s > 0.0_f32 {
+                    return u * (-2.0_f32 * ln(s) / s).max(0.0_f32).sqrt();
+                }
+            }
+        }
+
+        /// Exponential variate with the given `rate`.
+        #[inline]
+        pub fn exponential<R: RngCore + ?Sized>(rng: &mut R, rate: f32) -> f32 {
+            (-ln(open01(rng))).max(0.0_f32) / rate
+        }
+
+        /// Gamma variate with given `shape` and `rate`, using the Marsaglia–Tsang method.
+        ///
+        /// Shapes below one are boosted by one and corrected with a uniform power.
+        #[inline]
+        pub fn gamma<R: RngCore + ?Sized>(rng: &mut R, shape: f32, rate: f32) -> f32 {
+            if shape < 1.0_f32 {
+                let boost = pow(open01(rng), 1.0_f32 / shape);
+                return gamma(rng, shape + 1.0_f32, rate) * boost;
+            }
+            let d = shape - 1.0_f32 / 3.0_f32;
+            let c = 1.0_f32 / (9.0_f32 * d).sqrt();
+            loop {
+                let x = normal(rng);
+                let v = 1.0_f32 + c * x;
+                if v <= 0.0_f32 {
+                    continue;
+                }
+                let v = v * v * v;
+                let u = open01(rng);
+                let x2 = x * x;
+                if u < 1.0_f32 - 0.0331_f32 * x2 * x2
+                    || ln(u) < 0.5_f32 * x2 + d * (1.0_f32 - v + ln(v))
+                {
+                    return d * v / rate;
+                }
+            }
+        }
+
+        /// Beta variate with shape parameters `a` and `b`.
+        #[inline]
+        pub fn beta<R: RngCore + ?Sized>(rng: &mut R, a: f32, b: f32) -> f32 {
+            let x = gamma(rng, a, 1.0_f32);
+            let y = gamma(rng, b, 1.0_f32);
+            x / (x + y)
+        }
+
+        /// Dirichlet variate with concentration `alpha`, written into `out`.
+        #[inline]
+        pub fn dirichlet<R: RngCore + ?Sized>(rng: &mut R, alpha: &[f32], out: &mut [f32]) {
+            assert_eq!(alpha.len(), out.len());
+            let mut sum = 0.0_f32;
+            for (y, &a) in out.iter_mut().zip(alpha) {
+                *y = gamma(rng, a, 1.0_f32);
+                sum += *y;
+            }
+            let inv = 1.0_f32 / sum;
+            for y in out.iter_mut() {
+                *y *= inv;
+            }
+        }
+
+        /// Gumbel variate with given `location` and `scale`.
+        #[inline]
+        pub fn gumbel<R: RngCore + ?Sized>(rng: &mut R, location: f32, scale: f32) -> f32 {
+            location - scale * ln((-ln(open01(rng))).max(f32::MIN_POSITIVE))
+        }
+
+        /// Relaxed one-hot sample from the Gumbel-softmax (Concrete) distribution, written
+        /// into `out`.
+        ///
+        /// As the `temperature` goes to zero the sample approaches a one-hot vector whose
+        /// hot index is distributed according to the softmax of `logits`.
+        #[inline]
+        pub fn gumbel_softmax<R: RngCore + ?Sized>(
+            rng: &mut R,
+            logits: &[f32],
+            temperature: f32,
+            out: &mut [f32],
+        ) {
+            assert_eq!(logits.len(), out.len());
+            let invt = 1.0_f32 / temperature;
+            let mut m = f32::NEG_INFINITY;
+            for (y, &l) in out.iter_mut().zip(logits) {
+                *y = (l + gumbel(rng, 0.0_f32, 1.0_f32)) * invt;
+                m = m.max(*y);
+            }
+            let mut sum = 0.0_f32;
+            for y in out.iter_mut() {
+                *y = exp(*y - m);
+                sum += *y;
+            }
+            let inv = 1.0_f32 / sum;
+            for y in out.iter_mut() {
+                *y *= inv;
+            }
+        }
+    };
+}
+
+/// Samplers built on [`crate::fast`] approximations.
+pub mod fast {
+    sample_impl!(fast);
+}
+
+/// Samplers built on [`crate::faster`] approximations.
+pub mod faster {
+    sample_impl!(faster);
+}
@@ -0,0 +1,258 @@
+//! All functions subtract the maximum before exponentiating, so they are stable for
+//! arbitrarily large logits.
+//!
+//! [`OnlineLogSumExp`] and [`OnlineSoftmax`] compute the same quantities in a single pass over a
+//! stream of scores, keeping a running maximum and a sum rescaled to it.
+//! Accumulators over parts of a stream can be merged, e.g. after a parallel reduction.
+//!
+//! [`OnlineLogSumExp`]: crate::softmax::fast::OnlineLogSumExp
+//! [`OnlineSoftmax`]: crate::softmax::fast::OnlineSoftmax
+
+macro_rules! softmax_impl {
+    ($tier:ident) => {
+        use crate::$tier::{exp, ln};
+
+        #[inline]
+        fn max(x: &[f32]) -> f32 {
+            x.iter().fold(f32::NEG_INFINITY, |m, &v| m.max(v))
+        }
+
+        /// Natural logarithm of the sum of exponentials of `x`.
+        ///
+        /// Returns negative infinity for an empty slice.
+        #[inline]
+        pub fn logsumexp(x: &[f32]) -> f32 {
+            let m = max(x);
+            if m == f32::NEG_INFINITY {
+                return m;
+            }
+            let sum: f32 = x.iter().map(|&v| exp(v - m)).sum();
+            m + ln(sum)
+        }
+
+        /// Normalized exponentials of `x`, written into `out`.
+        ///
+        /// Writes zeros if no element is finite, like [`OnlineSoftmax`].
+        #[inline]
+        pub fn softmax(x: &[f32], out: &mut [f32]) {
+            assert_eq!(x.len(), out.len());
+            let m = max(x);
+            if m == f32::NEG_INFINITY {
+                out.fill(0.0_f32);
+                return;
+            }
+            let mut sum = 0.0_f32;
+            for (y, &v) in out.iter_mut().zip(x) {
+                *y = exp(v - m);
+                sum += *y;
+            }
+            let inv = 1.0_f32 / sum;
+            for y in out.iter_mut() {
+                *y *= inv;
+            }
+        }
+
+        /// Normalized exponentials of `x / temperature`, written into `out`.
+        ///
+        /// Temperatures below one sharpen the distribution, above one flatten it.
+        /// Writes zeros if no element is finite, like [`softmax`].
+        #[inline]
+        pub fn softmax_with_temperature(x: &[f32], temperature: f32, out: &mut [f32]) {
+            assert_eq!(x.len(), out.len());
+            let invt = 1.0_f32 / temperature;
+            let m = max(x);
+            if m == f32::NEG_INFINITY {
+                out.fill(0.0_f32);
+                return;
+            }
+            let mut sum = 0.0_f32;
+            for (y, &v) in out.iter_mut().zip(x) {
+                *y = exp((v - m) * invt);
+                sum += *y;
+            }
+            let inv = 1.0_f32 / sum;
+            for y in out.iter_mut() {
+                *y *= inv;
+            }
+        }
+
+        /// Logarithms of the normalized exponentials of `x`, written into `out`.
+        ///
+        /// Writes negative infinity if no element is finite, the logarithm of [`softmax`].
+        #[inline]
+        pub fn log_softmax(x: &[f32], out: &mut [f32]) {
+            assert_eq!(x.len(), out.len());
+            let lse = logsumexp(x);
+            if lse == f32::NEG_INFINITY {
+                out.fill(lse);
+                return;
+            }
+            for (y, &v) in out.iter_mut().zip(x) {
+                *y = v - lse;
+            }
+        }
+
+        /// Streaming log-sum-exp accumulator.
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        pub struct OnlineLogSumExp {
+            max: f32,
+            sum: f32,
+        }
+
+        impl Default for OnlineLogSumExp {
+            #[inline]
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl OnlineLogSumExp {
+            /// Empty accumulator.
+            #[inline]
+            pub fn new() -> Self {
+                OnlineLogSumExp {
+                    max: f32::NEG_INFINITY,
+                    sum: 0.0_f32,
+                }
+            }
+
+            /// Adds `x` and returns the factors by which the previous sum was rescaled and with
+            /// which `exp(x)` entered it.
+            #[inline]
+            fn update(&mut self, x: f32) -> (f32, f32) {
+                if x == f32::NEG_INFINITY {
+                    (1.0_f32, 0.0_f32)
+                } else if x > self.max {
+                    let scale = if self.max == f32::NEG_INFINITY {
+                        0.0_f32
+                    } else {
+                        exp(self.max - x)
+                    };
+                    self.sum = self.sum * scale + 1.0_f32;
+                    self.max = x;
+                    (scale, 1.0_f32)
+                } else {
+                    let weight = exp(x - self.max);
+                    self.sum += weight;
+                    (1.0_f32, weight)
+                }
+            }
+
+            /// Rescales both accumulators to their common maximum and returns the factors for
+            /// `self` and `other`.
+            #[inline]
+            fn combine(&mut self, other: &Self) -> (f32, f32) {
+                if other.max == f32::NEG_INFINITY {
+                    (1.0_f32, 0.0_f32)
+                } else if self.max == f32::NEG_INFINITY {
+                    *self = *other;
+                    (0.0_f32, 1.0_f32)
+                } else if other.max > self.max {
+                    let scale = exp(self.max - other.max);
+                    self.sum = self.sum * scale + other.sum;
+                    self.max = other.max;
+                    (scale, 1.0_f32)
+                } else {
+                    let scale = exp(other.max - self.max);
+                    self.sum += other.sum * scale;
+                    (1.0_f32, scale)
+                }
+            }
+
+            /// Adds the score `x`.
+            #[inline]
+            pub fn push(&mut self, x: f32) {
+                self.update(x);
+            }
+
+            /// Adds all scores seen by `other`.
+            #[inline]
+            pub fn merge(&mut self, other: &Self) {
+                self.combine(other);
+            }
+
+            /// Natural logarithm of the sum of exponentials of all scores.
+            ///
+            /// Returns negative infinity if no finite score was pushed.
+            #[inline]
+            pub fn finish(&self) -> f32 {
+                if self.max == f32::NEG_INFINITY {
+                    self.max
+                } else {
+                    self.max + ln(self.sum)
+                }
+            }
+        }
+
+        /// Streaming softmax-weighted average of value vectors, as in attention.
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct OnlineSoftmax {
+            lse: OnlineLogSumExp,
+            acc: Vec<f32>,
+        }
+
+        impl OnlineSoftmax {
+            /// Empty accumulator for value vectors of length `dim`.
+            #[inline]
+            pub fn new(dim: usize) -> Self {
+                OnlineSoftmax {
+                    lse: OnlineLogSumExp::new(),
+                    acc: vec![0.0_f32; dim],
+                }
+            }
+
+            /// Adds `value` with the score `x`.
+            ///
+            /// Panics if `value` has a different length than the accumulator.
+            #[inline]
+            pub fn push(&mut self, x: f32, value: &[f32]) {
+                assert_eq!(self.acc.len(), value.len());
+                let (scale, weight) = self.lse.update(x);
+                for (a, &v) in self.acc.iter_mut().zip(value) {
+                    *a = *a * scale + weight * v;
+                }
+            }
+
+            /// Adds all values seen by `other`.
+            ///
+            /// Panics if the accumulators have different lengths.
+            #[inline]
+            pub fn merge(&mut self, other: &Self) {
+                assert_eq!(self.acc.len(), other.acc.len());
+                let (scale, weight) = self.lse.combine(&other.lse);
+                for (a, &v) in self.acc.iter_mut().zip(&other.acc) {
+                    *a = *a * scale + weight * v;
+                }
+            }
+
+            /// Writes the softmax-weighted average of the values into `out` and returns the
+            /// log-sum-exp of the scores.
+            ///
+            /// The average is zero if no finite score was pushed.
+            /// Panics if `out` has a different length than the accumulator.
+            #[inline]
+            pub fn finish(&self, out: &mut [f32]) -> f32 {
+                assert_eq!(self.acc.len(), out.len());
+                let inv = if self.lse.sum > 0.0_f32 {
+                    1.0_f32 / self.lse.sum
+                } else {
+                    0.0_f32
+                };
+                for (y, &a) in out.iter_mut().zip(&self.acc) {
+                    *y = a * inv;
+                }
+                self.lse.finish()
+            }
+        }
+    };
+}
+
+/// Softmax built on [`crate::fast`] approximations.
+pub mod fast {
+    softmax_impl!(fast);
+}
+
+/// Softmax built on [`crate::faster`] approximations.
+pub mod faster {
+    softmax_impl!(faster);
+}
@@ -0,0 +1,139 @@
+//! The approximations under names stating a guaranteed bound on their error, in the style of
+//! [SLEEF](https://sleef.org).
+//!
+//! The suffix `_uN` of a name promises an error of at most N/10 units in the last place (ULP)
+//! of the exact result: `exp_u10` is within 1.0 ULP and `exp_u10000` within 1000 ULP. The
+//! unit is the spacing of `f32` values in the binade of the exact result. Functions can then be
+//! picked by contract rather than by tier.
+//!
+//! The bounds are against [`crate::reference`] over the domain listed in [`crate::registry`].
+//! [`GUARANTEES`] lists the functions with their bounds and domains. The tests run on every
+//! change check every 12289th float of each domain, so a float in between could exceed its
+//! bound unnoticed. The `exhaustive` CI job checks all of them with
+//! `cargo test --release -p fastapprox_tests --test ulp -- --ignored`, but it takes hours and
+//! only runs weekly or on demand, after changes are merged. The `_u10` functions are
+//! [`crate::precise`] under another name. The `fast` tier appears only for functions whose
+//! error is relative over the whole domain, since an absolute error has no bound in ULP near a
+//! zero of the function. The `faster` tier does not appear, as its errors of a few percent
+//! would make for bounds of hundreds of thousands of ULP.
+//!
+//! # Examples
+//!
+//! ```
+//! use fastapprox::ulp;
+//!
+//! assert_eq!(ulp::exp_u10(1.0), std::f32::consts::E);
+//! // The spacing of floats around e is 2^-22.
+//! assert!((ulp::exp_u10000(1.0) - std::f32::consts::E).abs() <= 1000.0 * 2f32.powi(-22));
+//! ```
+//!
+//! [`GUARANTEES`]: crate::ulp::GUARANTEES
+
+use crate::registry::{self, FunctionInfo, Tier};
+use crate::{fast, precise};
+
+/// A function of this module and its bound.
+#[derive(Clone, Copy, Debug)]
+pub struct Guarantee {
+    /// Name of the function, such as `exp_u10`.
+    pub name: &'static str,
+    /// The function.
+    pub approx: fn(f32) -> f32,
+    /// Largest error in units in the last place of the exact result.
+    pub max_ulp: f64,
+    /// Tier of the approximation under the name.
+    pub tier: Tier,
+    /// Name of the approximation in its tier, such as `exp`.
+    pub function: &'static str,
+}
+
+impl Guarantee {
+    /// The approximation in the registry, with the reference and the domain of the bound.
+    pub fn info(&self) -> &'static FunctionInfo {
+        registry::get(self.tier, self.function).unwrap()
+    }
+}
+
+macro_rules! guarantees {
+    ($(
+        $name:ident => $module:ident::$function:ident, $tier:ident, $ulp:literal, $doc:literal;
+    )*) => {
+        $(
+            #[doc = concat!(
+                $doc, ", within ", stringify!($ulp), " ULP over its domain in the registry.\n\n",
+                "Same as [`", stringify!($module), "::", stringify!($function), "`].",
+            )]
+            #[inline]
+            pub fn $name(x: f32) -> f32 {
+                $module::$function(x)
+            }
+        )*
+
+        /// Every function of this module with its bound.
+        pub const GUARANTEES: &[Guarantee] = &[
+            $(
+                Guarantee {
+                    name: stringify!($name),
+                    approx: $name,
+                    max_ulp: $ulp,
+                    tier: Tier::$tier,
+                    function: stringify!($function),
+                },
+            )*
+        ];
+    };
+}
+
+guarantees! {
+    log2_u10 => precise::log2, Precise, 1.0, "Base 2 logarithm";
+    ln_u10 => precise::ln, Precise, 1.0, "Natural logarithm";
+    pow2_u10 => precise::pow2, Precise, 1.0, "2 raised to a floating point power";
+    exp_u10 => precise::exp, Precise, 1.0, "Exponential function";
+    sigmoid_u10 => precise::sigmoid, Precise, 1.0, "Sigmoid function";
+    rsqrt_u10 => precise::rsqrt, Precise, 1.0, "Inverse square root";
+    ln_gamma_u10 => precise::ln_gamma, Precise, 1.0, "Natural logarithm of the Gamma function";
+    digamma_u10 => precise::digamma, Precise, 1.0, "Digamma function";
+    erfc_u10 => precise::erfc, Precise, 1.0, "Complementary error function";
+    erf_u10 => precise::erf, Precise, 1.0, "Error function";
+    erf_inv_u10 => precise::erf_inv, Precise, 1.0, "Inverse error function";
+    sinh_u10 => precise::sinh, Precise, 1.0, "Hyperbolic sine function";
+    cosh_u10 => precise::cosh, Precise, 1.0, "Hyperbolic cosine function";
+    tanh_u10 => precise::tanh, Precise, 1.0, "Hyperbolic tangent function";
+    lambertw_u10 => precise::lambertw, Precise, 1.0, "Lambert W function";
+    lambertwexpx_u10 => precise::lambertwexpx, Precise, 1.0, "Exponent of Lambert W function";
+    sin_u10 => precise::sin, Precise, 1.0, "Sine in radians";
+    sinfull_u10 => precise::sinfull, Precise, 1.0, "Sine in radians";
+    cos_u10 => precise::cos, Precise, 1.0, "Cosine in radians";
+    cosfull_u10 => precise::cosfull, Precise, 1.0, "Cosine in radians";
+    tan_u10 => precise::tan, Precise, 1.0, "Tangent in radians";
+    tanfull_u10 => precise::tanfull, Precise, 1.0, "Tangent in radians";
+    erfcx_u10 => precise::erfcx, Precise, 1.0, "Scaled complementary error function";
+    ln_erfc_u10 => precise::ln_erfc, Precise, 1.0,
+        "Natural logarithm of the complementary error function";
+    zeta_u10 => precise::zeta, Precise, 1.0, "Riemann zeta function";
+    exp_int_e1_u10 => precise::exp_int_e1, Precise, 1.0, "Exponential integral E1";
+    exp_int_ei_u10 => precise::exp_int_ei, Precise, 1.0, "Exponential integral Ei";
+    log_int_u10 => precise::log_int, Precise, 1.0, "Logarithmic integral li";
+    bessel_i0_u10 => precise::bessel_i0, Precise, 1.0,
+        "Modified Bessel function of the first kind of order zero";
+    bessel_i1_u10 => precise::bessel_i1, Precise, 1.0,
+        "Modified Bessel function of the first kind of order one";
+    ln_bessel_i0_u10 => precise::ln_bessel_i0, Precise, 1.0,
+        "Natural logarithm of the modified Bessel function of the first kind of order zero";
+    bessel_i1_over_i0_u10 => precise::bessel_i1_over_i0, Precise, 1.0,
+        "Ratio of modified Bessel functions of the first kind I1(x) / I0(x)";
+    bessel_i1_over_i0_u250 => fast::bessel_i1_over_i0, Fast, 25.0,
+        "Ratio of modified Bessel functions of the first kind I1(x) / I0(x)";
+    rsqrt_u1000 => fast::rsqrt, Fast, 100.0, "Inverse square root";
+    pow2_u10000 => fast::pow2, Fast, 1000.0, "2 raised to a floating point power";
+    exp_u10000 => fast::exp, Fast, 1000.0, "Exponential function";
+    cosh_u10000 => fast::cosh, Fast, 1000.0, "Hyperbolic cosine function";
+    zeta_u10000 => fast::zeta, Fast, 1000.0, "Riemann zeta function";
+    sigmoid_u15000 => fast::sigmoid, Fast, 1500.0, "Sigmoid function";
+    erfcx_u15000 => fast::erfcx, Fast, 1500.0, "Scaled complementary error function";
+    bessel_i0_u15000 => fast::bessel_i0, Fast, 1500.0,
+        "Modified Bessel function of the first kind of order zero";
+    bessel_i1_u15000 => fast::bessel_i1, Fast, 1500.0,
+        "Modified Bessel function of the first kind of order one";
+    log_int_u35000 => fast::log_int, Fast, 3500.0, "Logarithmic integral li";
+}
@@ -0,0 +1,175 @@
+//! Kernels update `weights` in place from the gradients in `grads`, along with their per-weight
+//! optimizer state.
+//! Besides [`fast`] and [`faster`], they are available in the [`exact`] tier built on `std`,
+//! which serves as a reference for differential testing.
+//!
+//! [`fast`]: crate::update::fast
+//! [`faster`]: crate::update::faster
+//! [`exact`]: crate::update::exact
+
+/// Hyperparameters of the Adam optimizer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Adam {
+    /// Step size.
+    pub learning_rate: f32,
+    /// Decay rate of the first moment estimate.
+    pub beta1: f32,
+    /// Decay rate of the second moment estimate.
+    pub beta2: f32,
+    /// Regularizer added to the square root of the second moment estimate, as in Kingma & Ba.
+    pub eps: f32,
+}
+
+impl Default for Adam {
+    fn default() -> Self {
+        Adam {
+            learning_rate: 0.001,
+            beta1: 0.9,
+            beta2: 0.999,
+            eps: 1e-8,
+        }
+    }
+}
+
+macro_rules! update_impl {
+    () => {
+        use super::Adam;
+
+        /// Square root computed as `x · rsqrt(x)`, and zero for zero.
+        #[inline]
+        fn sqrt(x: f32) -> f32 {
+            if x > 0.0_f32 {
+                x * rsqrt(x)
+            } else {
+                0.0_f32
+            }
+        }
+
+        /// AdaGrad update.
+        ///
+        /// Accumulates squared gradients in `accum` and divides each step by
+        /// `sqrt(accum) + eps`, with `eps` outside the square root as in [`adam`].
+        #[inline]
+        pub fn adagrad(
+            weights: &mut [f32],
+            grads: &[f32],
+            accum: &mut [f32],
+            learning_rate: f32,
+            eps: f32,
+        ) {
+            assert_eq!(weights.len(), grads.len());
+            assert_eq!(weights.len(), accum.len());
+            for ((w, &g), a) in weights.iter_mut().zip(grads).zip(accum.iter_mut()) {
+                *a += g * g;
+                *w -= learning_rate * g / (sqrt(*a) + eps);
+            }
+        }
+
+        /// RMSProp update.
+        ///
+        /// Keeps an exponential moving average of squared gradients with the given `decay` in
+        /// `mean_square`, and divides each step by `sqrt(mean_square) + eps` as in [`adam`].
+        #[inline]
+        pub fn rmsprop(
+            weights: &mut [f32],
+            grads: &[f32],
+            mean_square: &mut [f32],
+            learning_rate: f32,
+            decay: f32,
+            eps: f32,
+        ) {
+            assert_eq!(weights.len(), grads.len());
+            assert_eq!(weights.len(), mean_square.len());
+            for ((w, &g), v) in weights.iter_mut().zip(grads).zip(mean_square.iter_mut()) {
+                *v = decay * *v + (1.0_f32 - decay) * g * g;
+                *w -= learning_rate * g / (sqrt(*v) + eps);
+            }
+        }
+
+        /// Adam update for the given one-based `step`.
+        ///
+        /// Keeps the first and second moment estimates in `m` and `v`, and steps by
+        /// `m̂ / (sqrt(v̂) + eps)` as in Kingma & Ba, with the square root computed as
+        /// `v̂ · rsqrt(v̂)`.
+        /// The bias corrections are computed once per call with `powi`, since approximating
+        /// `1 - beta^step` loses all precision for `beta` close to one.
+        #[inline]
+        pub fn adam(
+            weights: &mut [f32],
+            grads: &[f32],
+            m: &mut [f32],
+            v: &mut [f32],
+            step: u32,
+            params: &Adam,
+        ) {
+            assert_eq!(weights.len(), grads.len());
+            assert_eq!(weights.len(), m.len());
+            assert_eq!(weights.len(), v.len());
+            let t = step.min(i32::MAX as u32) as i32;
+            let c1 = 1.0_f32 / (1.0_f32 - params.beta1.powi(t));
+            let c2 = 1.0_f32 / (1.0_f32 - params.beta2.powi(t));
+            for (((w, &g), m), v) in weights
+                .iter_mut()
+                .zip(grads)
+                .zip(m.iter_mut())
+                .zip(v.iter_mut())
+            {
+                *m = params.beta1 * *m + (1.0_f32 - params.beta1) * g;
+                *v = params.beta2 * *v + (1.0_f32 - params.beta2) * g * g;
+                *w -= params.learning_rate * c1 * *m / (sqrt(c2 * *v) + params.eps);
+            }
+        }
+
+        /// Vowpal Wabbit adaptive update with the `power_t` exponent.
+        ///
+        /// Accumulates squared gradients in `accum` and scales each step by `accum^(-power_t)`.
+        /// With `power_t = 0.5` this is AdaGrad without a regularizer.
+        /// Weights whose accumulator is still zero are left untouched.
+        #[inline]
+        pub fn power_t(
+            weights: &mut [f32],
+            grads: &[f32],
+            accum: &mut [f32],
+            learning_rate: f32,
+            power_t: f32,
+        ) {
+            assert_eq!(weights.len(), grads.len());
+            assert_eq!(weights.len(), accum.len());
+            for ((w, &g), a) in weights.iter_mut().zip(grads).zip(accum.iter_mut()) {
+                *a += g * g;
+                if *a > 0.0_f32 {
+                    *w -= learning_rate * g * pow(*a, -power_t);
+                }
+            }
+        }
+    };
+}
+
+/// Update kernels built on [`crate::fast`] approximations.
+pub mod fast {
+    use crate::fast::{pow, rsqrt};
+
+    update_impl!();
+}
+
+/// Update kernels built on [`crate::faster`] approximations.
+pub mod faster {
+    use crate::faster::{pow, rsqrt};
+
+    update_impl!();
+}
+
+/// Update kernels built on `std`, for reference.
+pub mod exact {
+    #[inline]
+    fn pow(x: f32, p: f32) -> f32 {
+        x.powf(p)
+    }
+
+    #[inline]
+    fn rsqrt(x: f32) -> f32 {
+        x.sqrt().recip()
+    }
+
+    update_impl!();
+}
@@ -5,7 +5,8 @@ extern crate special;
 extern crate statrs;
 
 use bencher::Bencher;
-use fastapprox::{fast, faster};
+use fastapprox::lut::{Interpolation, Lut};
+use fastapprox::{activation, fast, faster, logspace, poly, precise, softmax, update};
 use statrs::function::{erf, gamma};
 
 const ITERATIONS: u32 = 1000;
@@ -14,6 +15,42 @@ fn run<F: Fn(f32) -> f32>(bench: &mut Bencher, cb: F) {
     bench.iter(|| (0..ITERATIONS).fold(0.0, |a, b| a + cb(b as f32)))
 }
 
+/// Like `run`, with the inputs spread over `[lo, hi]` instead of counting up from zero, for
+/// functions that saturate, and for lookup tables, which would otherwise only hit their edges.
+fn run_between<F: Fn(f32) -> f32>(bench: &mut Bencher, lo: f32, hi: f32, cb: F) {
+    let step = (hi - lo) / ITERATIONS as f32;
+    bench.iter(|| (0..ITERATIONS).fold(0.0, |a, b| a + cb(lo + b as f32 * step)))
+}
+
+fn sigmoid_exact(x: f32) -> f32 {
+    1.0_f32 / (1.0_f32 + (-x).exp())
+}
+
+fn lut<F: Fn(f32) -> f32>(lo: f32, hi: f32, f: F, interpolation: Interpolation) -> Lut<256> {
+    Lut::new(lo, hi, f).with_interpolation(interpolation)
+}
+
+fn run_slice<F: Fn(&[f32], &mut [f32])>(bench: &mut Bencher, cb: F) {
+    let xs: Vec<f32> = (0..ITERATIONS).map(|b| (b % 40) as f32 * 0.5).collect();
+    let mut out = vec![0.0_f32; xs.len()];
+    bench.iter(|| {
+        cb(&xs, &mut out);
+        out[0]
+    })
+}
+
+fn run_update<F: Fn(&mut [f32], &[f32], &mut [f32])>(bench: &mut Bencher, cb: F) {
+    let grads: Vec<f32> = (0..ITERATIONS)
+        .map(|b| (b % 40) as f32 * 0.05 - 1.0)
+        .collect();
+    let mut weights = vec![0.0_f32; grads.len()];
+    let mut accum = vec![1.0_f32; grads.len()];
+    bench.iter(|| {
+        cb(&mut weights, &grads, &mut accum);
+        weights[0]
+    })
+}
+
 fn log2_std(bench: &mut Bencher) {
     run(bench, |b| b.log2())
 }
@@ -26,6 +63,34 @@ fn log2_faster(bench: &mut Bencher) {
     run(bench, faster::log2)
 }
 
+fn log2_precise(bench: &mut Bencher) {
+    run(bench, precise::log2)
+}
+
+fn log2_deg1(bench: &mut Bencher) {
+    run(bench, poly::log2_deg::<1>)
+}
+
+fn log2_deg2(bench: &mut Bencher) {
+    run(bench, poly::log2_deg::<2>)
+}
+
+fn log2_deg3(bench: &mut Bencher) {
+    run(bench, poly::log2_deg::<3>)
+}
+
+fn log2_deg4(bench: &mut Bencher) {
+    run(bench, poly::log2_deg::<4>)
+}
+
+fn log2_deg5(bench: &mut Bencher) {
+    run(bench, poly::log2_deg::<5>)
+}
+
+fn log2_deg6(bench: &mut Bencher) {
+    run(bench, poly::log2_deg::<6>)
+}
+
 fn ln_std(bench: &mut Bencher) {
     run(bench, |b| b.ln())
 }
@@ -38,6 +103,10 @@ fn ln_faster(bench: &mut Bencher) {
     run(bench, faster::ln);
 }
 
+fn ln_precise(bench: &mut Bencher) {
+    run(bench, precise::ln);
+}
+
 fn exp_std(bench: &mut Bencher) {
     run(bench, |b| b.exp())
 }
@@ -50,16 +119,48 @@ fn exp_faster(bench: &mut Bencher) {
     run(bench, faster::exp);
 }
 
+fn exp_precise(bench: &mut Bencher) {
+    run(bench, precise::exp);
+}
+
 fn pow2_std(bench: &mut Bencher) {
     run(bench, |b| 2.0_f32.powf(b))
 }
 
 fn pow2_fast(bench: &mut Bencher) {
-    run(bench, |b| fast::pow2(b))
+    run(bench, fast::pow2)
 }
 
 fn pow2_faster(bench: &mut Bencher) {
-    run(bench, |b| faster::pow2(b))
+    run(bench, faster::pow2)
+}
+
+fn pow2_precise(bench: &mut Bencher) {
+    run(bench, precise::pow2)
+}
+
+fn pow2_deg1(bench: &mut Bencher) {
+    run(bench, poly::exp2_deg::<1>)
+}
+
+fn pow2_deg2(bench: &mut Bencher) {
+    run(bench, poly::exp2_deg::<2>)
+}
+
+fn pow2_deg3(bench: &mut Bencher) {
+    run(bench, poly::exp2_deg::<3>)
+}
+
+fn pow2_deg4(bench: &mut Bencher) {
+    run(bench, poly::exp2_deg::<4>)
+}
+
+fn pow2_deg5(bench: &mut Bencher) {
+    run(bench, poly::exp2_deg::<5>)
+}
+
+fn pow2_deg6(bench: &mut Bencher) {
+    run(bench, poly::exp2_deg::<6>)
 }
 
 fn pow_std(bench: &mut Bencher) {
@@ -74,6 +175,10 @@ fn pow_faster(bench: &mut Bencher) {
     run(bench, |b| faster::pow(b, 1.5))
 }
 
+fn pow_precise(bench: &mut Bencher) {
+    run(bench, |b| precise::pow(b, 1.5))
+}
+
 fn sigmoid_std(bench: &mut Bencher) {
     run(bench, |b| 1.0_f32 / (1.0_f32 + (-b).exp()))
 }
@@ -86,6 +191,10 @@ fn sigmoid_faster(bench: &mut Bencher) {
     run(bench, faster::sigmoid)
 }
 
+fn sigmoid_precise(bench: &mut Bencher) {
+    run(bench, precise::sigmoid)
+}
+
 fn ln_gamma_special(bench: &mut Bencher) {
     run(bench, |b| special::Gamma::ln_gamma(b as f64).0 as f32)
 }
@@ -102,6 +211,10 @@ fn ln_gamma_faster(bench: &mut Bencher) {
     run(bench, faster::ln_gamma)
 }
 
+fn ln_gamma_precise(bench: &mut Bencher) {
+    run(bench, precise::ln_gamma)
+}
+
 fn digamma_special(bench: &mut Bencher) {
     run(bench, |b| special::Gamma::digamma(b as f64) as f32)
 }
@@ -118,6 +231,10 @@ fn digamma_faster(bench: &mut Bencher) {
     run(bench, faster::digamma)
 }
 
+fn digamma_precise(bench: &mut Bencher) {
+    run(bench, precise::digamma)
+}
+
 fn erfc_special(bench: &mut Bencher) {
     run(bench, |b| special::Error::compl_error(b as f64) as f32)
 }
@@ -130,6 +247,10 @@ fn erfc_faster(bench: &mut Bencher) {
     run(bench, faster::erfc)
 }
 
+fn erfc_precise(bench: &mut Bencher) {
+    run(bench, precise::erfc)
+}
+
 fn erf_statrs(bench: &mut Bencher) {
     run(bench, |b| erf::erf(b as f64) as f32)
 }
@@ -146,6 +267,10 @@ fn erf_faster(bench: &mut Bencher) {
     run(bench, faster::erf)
 }
 
+fn erf_precise(bench: &mut Bencher) {
+    run(bench, precise::erf)
+}
+
 fn erf_inv_statrs(bench: &mut Bencher) {
     run(bench, |b| erf::erf_inv(b as f64) as f32)
 }
@@ -158,6 +283,10 @@ fn erf_inv_faster(bench: &mut Bencher) {
     run(bench, faster::erf_inv)
 }
 
+fn erf_inv_precise(bench: &mut Bencher) {
+    run(bench, precise::erf_inv)
+}
+
 fn sinh_std(bench: &mut Bencher) {
     run(bench, |b| b.sinh())
 }
@@ -170,6 +299,10 @@ fn sinh_faster(bench: &mut Bencher) {
     run(bench, faster::sinh)
 }
 
+fn sinh_precise(bench: &mut Bencher) {
+    run(bench, precise::sinh)
+}
+
 fn cosh_std(bench: &mut Bencher) {
     run(bench, |b| b.cosh())
 }
@@ -182,6 +315,10 @@ fn cosh_faster(bench: &mut Bencher) {
     run(bench, faster::cosh)
 }
 
+fn cosh_precise(bench: &mut Bencher) {
+    run(bench, precise::cosh)
+}
+
 fn tanh_std(bench: &mut Bencher) {
     run(bench, |b| b.tanh())
 }
@@ -194,6 +331,64 @@ fn tanh_faster(bench: &mut Bencher) {
     run(bench, faster::tanh)
 }
 
+fn tanh_precise(bench: &mut Bencher) {
+    run(bench, precise::tanh)
+}
+
+fn sigmoid_range_std(bench: &mut Bencher) {
+    run_between(bench, -8.0, 8.0, sigmoid_exact)
+}
+
+fn sigmoid_range_fast(bench: &mut Bencher) {
+    run_between(bench, -8.0, 8.0, fast::sigmoid)
+}
+
+fn sigmoid_range_faster(bench: &mut Bencher) {
+    run_between(bench, -8.0, 8.0, faster::sigmoid)
+}
+
+fn sigmoid_lut_nearest(bench: &mut Bencher) {
+    let lut = lut(-8.0, 8.0, sigmoid_exact, Interpolation::Nearest);
+    run_between(bench, -8.0, 8.0, |x| lut.eval(x))
+}
+
+fn sigmoid_lut_linear(bench: &mut Bencher) {
+    let lut = lut(-8.0, 8.0, sigmoid_exact, Interpolation::Linear);
+    run_between(bench, -8.0, 8.0, |x| lut.eval(x))
+}
+
+fn sigmoid_lut_cubic(bench: &mut Bencher) {
+    let lut = lut(-8.0, 8.0, sigmoid_exact, Interpolation::Cubic);
+    run_between(bench, -8.0, 8.0, |x| lut.eval(x))
+}
+
+fn tanh_range_std(bench: &mut Bencher) {
+    run_between(bench, -5.0, 5.0, |x| x.tanh())
+}
+
+fn tanh_range_fast(bench: &mut Bencher) {
+    run_between(bench, -5.0, 5.0, fast::tanh)
+}
+
+fn tanh_range_faster(bench: &mut Bencher) {
+    run_between(bench, -5.0, 5.0, faster::tanh)
+}
+
+fn tanh_lut_nearest(bench: &mut Bencher) {
+    let lut = lut(-5.0, 5.0, f32::tanh, Interpolation::Nearest);
+    run_between(bench, -5.0, 5.0, |x| lut.eval(x))
+}
+
+fn tanh_lut_linear(bench: &mut Bencher) {
+    let lut = lut(-5.0, 5.0, f32::tanh, Interpolation::Linear);
+    run_between(bench, -5.0, 5.0, |x| lut.eval(x))
+}
+
+fn tanh_lut_cubic(bench: &mut Bencher) {
+    let lut = lut(-5.0, 5.0, f32::tanh, Interpolation::Cubic);
+    run_between(bench, -5.0, 5.0, |x| lut.eval(x))
+}
+
 fn lambertw_fast(bench: &mut Bencher) {
     run(bench, fast::lambertw)
 }
@@ -202,6 +397,10 @@ fn lambertw_faster(bench: &mut Bencher) {
     run(bench, faster::lambertw)
 }
 
+fn lambertw_precise(bench: &mut Bencher) {
+    run(bench, precise::lambertw)
+}
+
 fn lambertwexpx_fast(bench: &mut Bencher) {
     run(bench, fast::lambertwexpx)
 }
@@ -210,6 +409,10 @@ fn lambertwexpx_faster(bench: &mut Bencher) {
     run(bench, faster::lambertwexpx)
 }
 
+fn lambertwexpx_precise(bench: &mut Bencher) {
+    run(bench, precise::lambertwexpx)
+}
+
 fn sin_std(bench: &mut Bencher) {
     run(bench, |b| b.sin())
 }
@@ -226,10 +429,18 @@ fn sin_faster(bench: &mut Bencher) {
     run(bench, faster::sin)
 }
 
+fn sin_precise(bench: &mut Bencher) {
+    run(bench, precise::sin)
+}
+
 fn sinfull_faster(bench: &mut Bencher) {
     run(bench, faster::sinfull)
 }
 
+fn sinfull_precise(bench: &mut Bencher) {
+    run(bench, precise::sinfull)
+}
+
 fn cos_std(bench: &mut Bencher) {
     run(bench, |b| b.cos())
 }
@@ -246,10 +457,18 @@ fn cos_faster(bench: &mut Bencher) {
     run(bench, faster::cos)
 }
 
+fn cos_precise(bench: &mut Bencher) {
+    run(bench, precise::cos)
+}
+
 fn cosfull_faster(bench: &mut Bencher) {
     run(bench, faster::cosfull)
 }
 
+fn cosfull_precise(bench: &mut Bencher) {
+    run(bench, precise::cosfull)
+}
+
 fn tan_std(bench: &mut Bencher) {
     run(bench, |b| b.tan())
 }
@@ -266,75 +485,318 @@ fn tan_faster(bench: &mut Bencher) {
     run(bench, faster::tan)
 }
 
+fn tan_precise(bench: &mut Bencher) {
+    run(bench, precise::tan)
+}
+
 fn tanfull_faster(bench: &mut Bencher) {
     run(bench, faster::tanfull)
 }
 
+fn tanfull_precise(bench: &mut Bencher) {
+    run(bench, precise::tanfull)
+}
+
+fn erfcx_fast(bench: &mut Bencher) {
+    run(bench, fast::erfcx)
+}
+
+fn erfcx_faster(bench: &mut Bencher) {
+    run(bench, faster::erfcx)
+}
+
+fn erfcx_precise(bench: &mut Bencher) {
+    run(bench, precise::erfcx)
+}
+
+fn bessel_i0_fast(bench: &mut Bencher) {
+    run(bench, fast::bessel_i0)
+}
+
+fn bessel_i0_faster(bench: &mut Bencher) {
+    run(bench, faster::bessel_i0)
+}
+
+fn bessel_i0_precise(bench: &mut Bencher) {
+    run(bench, precise::bessel_i0)
+}
+
+fn ln_bessel_i0_fast(bench: &mut Bencher) {
+    run(bench, fast::ln_bessel_i0)
+}
+
+fn ln_bessel_i0_faster(bench: &mut Bencher) {
+    run(bench, faster::ln_bessel_i0)
+}
+
+fn ln_bessel_i0_precise(bench: &mut Bencher) {
+    run(bench, precise::ln_bessel_i0)
+}
+
+fn gelu_tanh_std(bench: &mut Bencher) {
+    run(bench, |b| {
+        0.5 * b * (1.0 + (0.797_884_6_f32 * (b + 0.044715 * b * b * b)).tanh())
+    })
+}
+
+fn gelu_tanh_fast(bench: &mut Bencher) {
+    run(bench, activation::fast::gelu_tanh)
+}
+
+fn gelu_tanh_faster(bench: &mut Bencher) {
+    run(bench, activation::faster::gelu_tanh)
+}
+
+fn mish_std(bench: &mut Bencher) {
+    run(bench, |b| b * b.exp().ln_1p().tanh())
+}
+
+fn mish_fast(bench: &mut Bencher) {
+    run(bench, activation::fast::mish)
+}
+
+fn mish_faster(bench: &mut Bencher) {
+    run(bench, activation::faster::mish)
+}
+
+fn softmax_std(bench: &mut Bencher) {
+    run_slice(bench, |xs, out| {
+        let mut sum = 0.0;
+        for (y, x) in out.iter_mut().zip(xs) {
+            *y = x.exp();
+            sum += *y;
+        }
+        for y in out.iter_mut() {
+            *y /= sum;
+        }
+    })
+}
+
+fn softmax_fast(bench: &mut Bencher) {
+    run_slice(bench, softmax::fast::softmax)
+}
+
+fn softmax_faster(bench: &mut Bencher) {
+    run_slice(bench, softmax::faster::softmax)
+}
+
+fn log_softmax_std(bench: &mut Bencher) {
+    run_slice(bench, |xs, out| {
+        let lse = xs.iter().map(|x| x.exp()).sum::<f32>().ln();
+        for (y, x) in out.iter_mut().zip(xs) {
+            *y = x - lse;
+        }
+    })
+}
+
+fn log_softmax_fast(bench: &mut Bencher) {
+    run_slice(bench, softmax::fast::log_softmax)
+}
+
+fn log_softmax_faster(bench: &mut Bencher) {
+    run_slice(bench, softmax::faster::log_softmax)
+}
+
+fn rsqrt_std(bench: &mut Bencher) {
+    run(bench, |b| (b + 1.0).sqrt().recip())
+}
+
+fn rsqrt_fast(bench: &mut Bencher) {
+    run(bench, |b| fast::rsqrt(b + 1.0))
+}
+
+fn rsqrt_faster(bench: &mut Bencher) {
+    run(bench, |b| faster::rsqrt(b + 1.0))
+}
+
+fn rsqrt_precise(bench: &mut Bencher) {
+    run(bench, |b| precise::rsqrt(b + 1.0))
+}
+
+fn adagrad_exact(bench: &mut Bencher) {
+    run_update(bench, |w, g, a| update::exact::adagrad(w, g, a, 0.1, 1e-8))
+}
+
+fn adagrad_fast(bench: &mut Bencher) {
+    run_update(bench, |w, g, a| update::fast::adagrad(w, g, a, 0.1, 1e-8))
+}
+
+fn adagrad_faster(bench: &mut Bencher) {
+    run_update(bench, |w, g, a| update::faster::adagrad(w, g, a, 0.1, 1e-8))
+}
+
+fn power_t_exact(bench: &mut Bencher) {
+    run_update(bench, |w, g, a| update::exact::power_t(w, g, a, 0.1, 0.4))
+}
+
+fn power_t_fast(bench: &mut Bencher) {
+    run_update(bench, |w, g, a| update::fast::power_t(w, g, a, 0.1, 0.4))
+}
+
+fn power_t_faster(bench: &mut Bencher) {
+    run_update(bench, |w, g, a| update::faster::power_t(w, g, a, 0.1, 0.4))
+}
+
+fn log_add_exp_exact(bench: &mut Bencher) {
+    use logspace::exact::LogF32;
+    run(bench, |b| (LogF32::from_ln(b) + LogF32::from_ln(-b)).ln())
+}
+
+fn log_add_exp_fast(bench: &mut Bencher) {
+    use logspace::fast::LogF32;
+    run(bench, |b| (LogF32::from_ln(b) + LogF32::from_ln(-b)).ln())
+}
+
+fn log_add_exp_faster(bench: &mut Bencher) {
+    use logspace::faster::LogF32;
+    run(bench, |b| (LogF32::from_ln(b) + LogF32::from_ln(-b)).ln())
+}
+
 benchmark_group!(
     benches,
     log2_std,
     log2_fast,
     log2_faster,
+    log2_precise,
+    log2_deg1,
+    log2_deg2,
+    log2_deg3,
+    log2_deg4,
+    log2_deg5,
+    log2_deg6,
     ln_std,
     ln_fast,
     ln_faster,
+    ln_precise,
     exp_std,
     exp_fast,
     exp_faster,
+    exp_precise,
     pow2_std,
     pow2_fast,
     pow2_faster,
+    pow2_precise,
+    pow2_deg1,
+    pow2_deg2,
+    pow2_deg3,
+    pow2_deg4,
+    pow2_deg5,
+    pow2_deg6,
     pow_std,
     pow_fast,
     pow_faster,
+    pow_precise,
     sigmoid_std,
     sigmoid_fast,
     sigmoid_faster,
+    sigmoid_precise,
     ln_gamma_special,
     ln_gamma_statrs,
     ln_gamma_fast,
     ln_gamma_faster,
+    ln_gamma_precise,
     digamma_special,
     digamma_statrs,
     digamma_fast,
     digamma_faster,
+    digamma_precise,
     erf_statrs,
     erf_special,
     erf_fast,
     erf_faster,
+    erf_precise,
     erfc_special,
     erfc_fast,
     erfc_faster,
+    erfc_precise,
     erf_inv_statrs,
     erf_inv_fast,
     erf_inv_faster,
+    erf_inv_precise,
     sinh_std,
     sinh_fast,
     sinh_faster,
+    sinh_precise,
     cosh_std,
     cosh_fast,
     cosh_faster,
+    cosh_precise,
     tanh_std,
     tanh_fast,
     tanh_faster,
+    tanh_precise,
+    sigmoid_range_std,
+    sigmoid_range_fast,
+    sigmoid_range_faster,
+    sigmoid_lut_nearest,
+    sigmoid_lut_linear,
+    sigmoid_lut_cubic,
+    tanh_range_std,
+    tanh_range_fast,
+    tanh_range_faster,
+    tanh_lut_nearest,
+    tanh_lut_linear,
+    tanh_lut_cubic,
     lambertw_fast,
     lambertw_faster,
+    lambertw_precise,
     lambertwexpx_fast,
     lambertwexpx_faster,
+    lambertwexpx_precise,
     sin_std,
     sin_fast,
     sinfull_fast,
     sin_faster,
+    sin_precise,
     sinfull_faster,
+    sinfull_precise,
     cos_std,
     cos_fast,
     cosfull_fast,
     cos_faster,
+    cos_precise,
     cosfull_faster,
+    cosfull_precise,
     tan_std,
     tan_fast,
     tanfull_fast,
     tan_faster,
-    tanfull_faster
+    tan_precise,
+    tanfull_faster,
+    tanfull_precise,
+    erfcx_fast,
+    erfcx_faster,
+    erfcx_precise,
+    bessel_i0_fast,
+    bessel_i0_faster,
+    bessel_i0_precise,
+    ln_bessel_i0_fast,
+    ln_bessel_i0_faster,
+    ln_bessel_i0_precise,
+    gelu_tanh_std,
+    gelu_tanh_fast,
+    gelu_tanh_faster,
+    mish_std,
+    mish_fast,
+    mish_faster,
+    softmax_std,
+    softmax_fast,
+    softmax_faster,
+    log_softmax_std,
+    log_softmax_fast,
+    log_softmax_faster,
+    rsqrt_std,
+    rsqrt_fast,
+    rsqrt_faster,
+    rsqrt_precise,
+    adagrad_exact,
+    adagrad_fast,
+    adagrad_faster,
+    power_t_exact,
+    power_t_fast,
+    power_t_faster,
+    log_add_exp_exact,
+    log_add_exp_fast,
+    log_add_exp_faster
 );
 benchmark_main!(benches);
@@ -1,7 +1,19 @@
 extern crate cc;
+extern crate fastapprox;
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+#[path = "tests/codegen/approximations.rs"]
+mod approximations;
 
 fn main() {
     cc::Build::new()
         .file("tests/c/fastapprox.c")
         .compile("fastapprox");
+
+    // Source compiled and checked by tests/codegen.rs.
+    let out = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out).join("codegen.rs"), approximations::source()).unwrap();
 }
@@ -0,0 +1,266 @@
+//! Sweeps of approximations over every `f32` in an interval, in parallel.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+/// Number of histogram bins: zero ULPs, then one bin per power of two up to 2³¹ and beyond.
+pub const HISTOGRAM_BINS: usize = 33;
+
+/// Number of consecutive `f32` values handed to a worker thread at once.
+const CHUNK: u64 = 1 << 16;
+
+/// Maps an `f32` to an integer with the same ordering, so that neighbouring floats map to
+/// neighbouring integers and ±0 both map to zero.
+pub fn ordinal(x: f32) -> i64 {
+    let bits = x.to_bits() as i32;
+    if bits < 0 {
+        -((bits & 0x7fff_ffff) as i64)
+    } else {
+        bits as i64
+    }
+}
+
+/// Inverse of [`ordinal`].
+pub fn from_ordinal(i: i64) -> f32 {
+    if i < 0 {
+        f32::from_bits((-i) as u32 | 0x8000_0000)
+    } else {
+        f32::from_bits(i as u32)
+    }
+}
+
+/// Distance between two finite floats in units in the last place.
+pub fn ulp_distance(a: f32, b: f32) -> u64 {
+    (ordinal(a) - ordinal(b)).unsigned_abs()
+}
+
+/// Error of `actual` in units in the last place of `exact`, the spacing of `f32` values in the
+/// binade of `exact`, as a fraction.
+///
+/// Unlike [`ulp_distance`] to the rounded `exact`, this is the measure of bounds such as
+/// "within 1.0 ULP", which a correctly rounded result meets with 0.5 to spare.
+pub fn ulp_error(actual: f32, exact: f64) -> f64 {
+    let exponent = ((exact.to_bits() >> 52) & 0x7ff) as i32 - 1023;
+    let ulp = 2.0_f64.powi(exponent.max(-126) - 23);
+    (actual as f64 - exact).abs() / ulp
+}
+
+/// Histogram bin of an error of `ulps` units in the last place.
+pub fn histogram_bin(ulps: u64) -> usize {
+    ((64 - ulps.leading_zeros()) as usize).min(HISTOGRAM_BINS - 1)
+}
+
+/// Human-readable range of ULP errors counted in a histogram bin.
+pub fn histogram_label(bin: usize) -> String {
+    match bin {
+        0 => "0".to_string(),
+        1 => "1".to_string(),
+        b if b == HISTOGRAM_BINS - 1 => format!(">={}", 1_u64 << (b - 1)),
+        b => format!("{}-{}", 1_u64 << (b - 1), (1_u64 << b) - 1),
+    }
+}
+
+/// Error statistics of an approximation against its reference.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stats {
+    /// Number of inputs compared.
+    pub count: u64,
+    /// Number of inputs skipped because the reference is not finite in single precision.
+    pub skipped: u64,
+    /// Number of inputs for which the approximation is not finite but the reference is.
+    pub invalid: u64,
+    /// Maximum error in units in the last place, and the input where it occurs.
+    pub max_ulp: (u64, f32),
+    /// Maximum [`ulp_error`], and the input where it occurs.
+    pub max_ulp_error: (f64, f32),
+    /// Maximum relative error, and the input where it occurs.
+    ///
+    /// Only inputs where the reference is a normal float take part.
+    pub max_rel: (f64, f32),
+    /// Maximum absolute error, and the input where it occurs.
+    pub max_abs: (f64, f32),
+    /// Counts of ULP errors, see [`histogram_bin`].
+    pub histogram: [u64; HISTOGRAM_BINS],
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Stats {
+            count: 0,
+            skipped: 0,
+            invalid: 0,
+            max_ulp: (0, f32::NAN),
+            max_ulp_error: (0.0, f32::NAN),
+            max_rel: (0.0, f32::NAN),
+            max_abs: (0.0, f32::NAN),
+            histogram: [0; HISTOGRAM_BINS],
+        }
+    }
+}
+
+impl Stats {
+    /// Records the approximation `actual` of `expected` at the input `x`.
+    pub fn record(&mut self, x: f32, actual: f32, expected: f64) {
+        let rounded = expected as f32;
+        if !rounded.is_finite() {
+            self.skipped += 1;
+            return;
+        }
+        self.count += 1;
+        if !actual.is_finite() {
+            self.invalid += 1;
+            return;
+        }
+        let ulps = ulp_distance(actual, rounded);
+        self.histogram[histogram_bin(ulps)] += 1;
+        if ulps > self.max_ulp.0 {
+            self.max_ulp = (ulps, x);
+        }
+        let error = ulp_error(actual, expected);
+        if error > self.max_ulp_error.0 {
+            self.max_ulp_error = (error, x);
+        }
+        let abs = (actual as f64 - expected).abs();
+        if abs > self.max_abs.0 {
+            self.max_abs = (abs, x);
+        }
+        if rounded.is_normal() {
+            let rel = abs / expected.abs();
+            if rel > self.max_rel.0 {
+                self.max_rel = (rel, x);
+            }
+        }
+    }
+
+    /// Combines the statistics of two disjoint sets of inputs.
+    pub fn merge(&mut self, other: &Stats) {
+        self.count += other.count;
+        self.skipped += other.skipped;
+        self.invalid += other.invalid;
+        if other.max_ulp.0 > self.max_ulp.0 {
+            self.max_ulp = other.max_ulp;
+        }
+        if other.max_ulp_error.0 > self.max_ulp_error.0 {
+            self.max_ulp_error = other.max_ulp_error;
+        }
+        if other.max_rel.0 > self.max_rel.0 {
+            self.max_rel = other.max_rel;
+        }
+        if other.max_abs.0 > self.max_abs.0 {
+            self.max_abs = other.max_abs;
+        }
+        for (a, b) in self.histogram.iter_mut().zip(&other.histogram) {
+            *a += b;
+        }
+    }
+}
+
+/// Statistics over the floats evaluated in the closed interval `[lo, hi]`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Interval {
+    /// Smallest float evaluated.
+    pub lo: f32,
+    /// Largest float evaluated.
+    pub hi: f32,
+    /// Statistics of the interval.
+    pub stats: Stats,
+}
+
+/// Settings of a sweep.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sweep {
+    /// Only every `stride`-th float of the domain is evaluated; one means all of them.
+    pub stride: u64,
+    /// Number of worker threads.
+    pub threads: usize,
+}
+
+impl Default for Sweep {
+    fn default() -> Self {
+        Sweep {
+            stride: 1,
+            threads: thread::available_parallelism().map_or(1, |n| n.get()),
+        }
+    }
+}
+
+impl Sweep {
+    /// Evaluates `approx` against `reference` at every `stride`-th float in `[lo, hi]`.
+    pub fn run<F, R>(&self, approx: F, reference: R, lo: f32, hi: f32) -> Stats
+    where
+        F: Fn(f32) -> f32 + Sync,
+        R: Fn(f64) -> f64 + Sync,
+    {
+        let mut intervals = self.run_intervals(approx, reference, lo, hi, 1);
+        intervals.pop().unwrap().stats
+    }
+
+    /// Like [`Sweep::run`], but splits `[lo, hi]` into `count` intervals holding the same number
+    /// of floats each and reports the statistics of every interval separately.
+    ///
+    /// Fewer intervals are returned if there are fewer than `count` floats to evaluate.
+    pub fn run_intervals<F, R>(
+        &self,
+        approx: F,
+        reference: R,
+        lo: f32,
+        hi: f32,
+        count: usize,
+    ) -> Vec<Interval>
+    where
+        F: Fn(f32) -> f32 + Sync,
+        R: Fn(f64) -> f64 + Sync,
+    {
+        let stride = self.stride.max(1);
+        let start = ordinal(lo);
+        let points = ((ordinal(hi) - start) as u64) / stride + 1;
+        let count = (count.max(1) as u64).min(points);
+        let chunks = points.div_ceil(CHUNK);
+        let at = |i: u64| from_ordinal(start + (i * stride) as i64);
+        // Index of the first point of interval `j`.
+        let first = |j: u64| (j as u128 * points as u128 / count as u128) as u64;
+        let interval_of = |i: u64| ((i as u128 + 1) * count as u128 - 1) / points as u128;
+        let next = AtomicU64::new(0);
+        let total = Mutex::new(vec![Stats::default(); count as usize]);
+        thread::scope(|scope| {
+            for _ in 0..self.threads.max(1) {
+                scope.spawn(|| {
+                    let mut stats = vec![Stats::default(); count as usize];
+                    loop {
+                        let chunk = next.fetch_add(1, Ordering::Relaxed);
+                        if chunk >= chunks {
+                            break;
+                        }
+                        let end = ((chunk + 1) * CHUNK).min(points);
+                        let mut i = chunk * CHUNK;
+                        while i < end {
+                            let j = interval_of(i) as usize;
+                            let stop = first(j as u64 + 1).min(end);
+                            for i in i..stop {
+                                let x = at(i);
+                                stats[j].record(x, approx(x), reference(x as f64));
+                            }
+                            i = stop;
+                        }
+                    }
+                    let mut total = total.lock().unwrap();
+                    for (t, s) in total.iter_mut().zip(&stats) {
+                        t.merge(s);
+                    }
+                });
+            }
+        });
+        total
+            .into_inner()
+            .unwrap()
+            .into_iter()
+            .enumerate()
+            .map(|(j, stats)| Interval {
+                lo: at(first(j as u64)),
+                hi: at(first(j as u64 + 1) - 1),
+                stats,
+            })
+            .collect()
+    }
+}
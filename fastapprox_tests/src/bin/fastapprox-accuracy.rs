@@ -0,0 +1,167 @@
+//! Sweeps every approximation in `fastapprox::registry` over all `f32` values in its domain and
+//! reports the worst-case errors against its reference. Functions of two arguments are swept
+//! over the first one for each fixed value of the second, and reported as `pow@2.5`.
+//!
+//! Usage: `fastapprox-accuracy [--stride N] [--threads N] [--intervals N]
+//! [--format text|csv|json] [--check BASELINE] [FILTER...]`
+//!
+//! Only functions whose qualified name, such as `fast::ln`, contains one of the filters are
+//! swept, all of them if none is given. A stride above one evaluates only every N-th float, for
+//! quick runs. The domain of every function is split into the given number of intervals with
+//! the same number of floats each, which are reported separately in the CSV and JSON formats.
+//!
+//! With `--check`, the results are compared against a baseline written earlier in CSV format,
+//! and the process fails if any function regressed. The checked-in baseline is regenerated by
+//!
+//! ```text
+//! fastapprox-accuracy --stride 65536 --intervals 4 --format csv > accuracy_baseline.csv
+//! ```
+
+use std::env;
+use std::fs;
+use std::process;
+use std::time::Instant;
+
+use fastapprox::registry::{ALL, BINARY};
+use fastapprox_tests::accuracy::{histogram_label, Stats, Sweep};
+use fastapprox_tests::report::{self, Record};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Csv,
+    Json,
+}
+
+fn usage() -> ! {
+    eprintln!(
+        "usage: fastapprox-accuracy [--stride N] [--threads N] [--intervals N] \
+         [--format text|csv|json] [--check BASELINE] [FILTER...]"
+    );
+    process::exit(2);
+}
+
+fn print(name: &str, domain: (f32, f32), stats: &Stats, seconds: f64) {
+    println!(
+        "{} on [{:e}, {:e}]: {} points, {} skipped, {} invalid, {:.1}s",
+        name, domain.0, domain.1, stats.count, stats.skipped, stats.invalid, seconds
+    );
+    println!(
+        "  max ulp {:>12} at x = {:e}",
+        stats.max_ulp.0, stats.max_ulp.1
+    );
+    println!(
+        "  max rel {:>12.3e} at x = {:e}",
+        stats.max_rel.0, stats.max_rel.1
+    );
+    println!(
+        "  max abs {:>12.3e} at x = {:e}",
+        stats.max_abs.0, stats.max_abs.1
+    );
+    let bins: Vec<String> = stats
+        .histogram
+        .iter()
+        .enumerate()
+        .filter(|(_, &n)| n > 0)
+        .map(|(bin, n)| format!("{}: {}", histogram_label(bin), n))
+        .collect();
+    println!("  ulp histogram {{{}}}", bins.join(", "));
+}
+
+fn main() {
+    let mut sweep = Sweep::default();
+    let mut intervals = 1;
+    let mut format = Format::Text;
+    let mut check = None;
+    let mut filters = Vec::new();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--stride" => {
+                sweep.stride = args
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .unwrap_or_else(|| usage())
+            }
+            "--threads" => {
+                sweep.threads = args
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .unwrap_or_else(|| usage())
+            }
+            "--intervals" => {
+                intervals = args
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .unwrap_or_else(|| usage())
+            }
+            "--format" => {
+                format = match args.next().as_deref() {
+                    Some("text") => Format::Text,
+                    Some("csv") => Format::Csv,
+                    Some("json") => Format::Json,
+                    _ => usage(),
+                }
+            }
+            "--check" => check = Some(args.next().unwrap_or_else(|| usage())),
+            "-h" | "--help" => usage(),
+            _ => filters.push(arg),
+        }
+    }
+
+    let baseline = check.map(|path| {
+        let csv = fs::read_to_string(&path).unwrap_or_else(|e| {
+            eprintln!("cannot read {}: {}", path, e);
+            process::exit(2);
+        });
+        report::from_csv(&csv).unwrap_or_else(|e| {
+            eprintln!("cannot parse {}: {}", path, e);
+            process::exit(2);
+        })
+    });
+
+    let mut records: Vec<Record> = Vec::new();
+    let mut run = |name: String, domain: (f32, f32), swept: &dyn Fn() -> Vec<Record>| {
+        if !filters.is_empty() && !filters.iter().any(|p| name.contains(p.as_str())) {
+            return;
+        }
+        let start = Instant::now();
+        let swept = swept();
+        if format == Format::Text {
+            let mut stats = Stats::default();
+            for r in &swept {
+                stats.merge(&r.interval.stats);
+            }
+            print(&name, domain, &stats, start.elapsed().as_secs_f64());
+        }
+        records.extend(swept);
+    };
+    for f in ALL {
+        run(f.qualified_name(), f.domain, &|| {
+            report::sweep(f, &sweep, intervals)
+        });
+    }
+    for f in BINARY {
+        for &y in f.fixed {
+            run(format!("{}@{}", f.qualified_name(), y), f.domain, &|| {
+                report::sweep_binary(f, y, &sweep, intervals)
+            });
+        }
+    }
+
+    match format {
+        Format::Text => {}
+        Format::Csv => print!("{}", report::to_csv(&records)),
+        Format::Json => print!("{}", report::to_json(&records)),
+    }
+
+    if let Some(baseline) = baseline {
+        let regressions = report::regressions(&baseline, &records);
+        for r in &regressions {
+            eprintln!("regression: {}", r);
+        }
+        if !regressions.is_empty() {
+            process::exit(1);
+        }
+    }
+}
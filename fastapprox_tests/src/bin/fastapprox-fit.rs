@@ -0,0 +1,132 @@
+//! Re-derives the constants of approximations taken from the C original.
+//!
+//! Usage: `fastapprox-fit [--method minimax|lsq] [--metric abs|rel|ulp] [--points N]
+//! [--iterations N] [--refine] [--stride N] [--radius N] [FORM...]`
+//!
+//! Fits every form in `fastapprox_tests::forms::FORMS` whose name contains one of the given
+//! filters, all of them if none is given, starting from the original constants. The metric
+//! defaults to the one of each form. With `--refine`, the fitted constants are rounded to `f32`
+//! and re-optimized for the maximum error of the single-precision kernel over every
+//! `stride`-th float of its inputs, trying neighbours up to `radius` units in the last place
+//! away.
+
+use std::env;
+use std::fmt::LowerExp;
+use std::process;
+
+use fastapprox_tests::fit::{refine_f32, Kernel, Metric, Model};
+use fastapprox_tests::forms::FORMS;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Method {
+    Minimax,
+    LeastSquares,
+}
+
+fn usage() -> ! {
+    eprintln!(
+        "usage: fastapprox-fit [--method minimax|lsq] [--metric abs|rel|ulp] [--points N] \
+         [--iterations N] [--refine] [--stride N] [--radius N] [FORM...]"
+    );
+    process::exit(2);
+}
+
+fn number<T: std::str::FromStr>(arg: Option<String>) -> T {
+    arg.and_then(|n| n.parse().ok()).unwrap_or_else(|| usage())
+}
+
+fn print_params<T: LowerExp>(label: &str, params: &[T], error: f64) {
+    println!("  {:<16} max error {:.6e}", label, error);
+    for (i, p) in params.iter().enumerate() {
+        println!("    c{} = {:e}", i, p);
+    }
+}
+
+fn main() {
+    let mut method = Method::Minimax;
+    let mut metric = None;
+    let mut points = 4000;
+    let mut iterations = 200;
+    let mut refine = false;
+    let mut stride = 64;
+    let mut radius = 1 << 10;
+    let mut filters = Vec::new();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--method" => {
+                method = match args.next().as_deref() {
+                    Some("minimax") => Method::Minimax,
+                    Some("lsq") => Method::LeastSquares,
+                    _ => usage(),
+                }
+            }
+            "--metric" => {
+                metric = match args.next().as_deref() {
+                    Some("abs") => Some(Metric::Absolute),
+                    Some("rel") => Some(Metric::Relative),
+                    Some("ulp") => Some(Metric::Ulp),
+                    _ => usage(),
+                }
+            }
+            "--points" => points = number(args.next()),
+            "--iterations" => iterations = number(args.next()),
+            "--refine" => refine = true,
+            "--stride" => stride = number(args.next()),
+            "--radius" => radius = number(args.next()),
+            "-h" | "--help" => usage(),
+            _ => filters.push(arg),
+        }
+    }
+
+    for form in FORMS {
+        if !filters.is_empty() && !filters.iter().any(|p| form.name.contains(p.as_str())) {
+            continue;
+        }
+        let model = Model {
+            metric: metric.unwrap_or(form.model.metric),
+            ..form.model
+        };
+        let kernel = Kernel {
+            metric: metric.unwrap_or(form.kernel.metric),
+            ..form.kernel
+        };
+        println!(
+            "{}: {} on [{}, {}], {:?} error",
+            form.name, form.formula, model.interval.0, model.interval.1, model.metric
+        );
+
+        let grid = model.grid(points);
+        let original = model.max_error(form.original, &grid);
+        let fit = match method {
+            Method::Minimax => model.minimax(form.original, points, iterations),
+            Method::LeastSquares => model.least_squares(form.original, points),
+        };
+        print_params("original", form.original, original.max_error);
+        print_params("fitted", &fit.params, fit.max_error);
+
+        if refine {
+            let inputs = form.inputs(stride);
+            let original: Vec<f32> = form.original.iter().map(|&p| p as f32).collect();
+            let rounded: Vec<f32> = fit.params.iter().map(|&p| p as f32).collect();
+            println!(
+                "  single-precision kernel over {} inputs in [{:e}, {:e}]",
+                inputs.len(),
+                form.inputs.0,
+                form.inputs.1
+            );
+            print_params(
+                "original f32",
+                &original,
+                kernel.max_error(&original, &inputs).0,
+            );
+            print_params(
+                "fitted f32",
+                &rounded,
+                kernel.max_error(&rounded, &inputs).0,
+            );
+            let (refined, error) = refine_f32(&kernel, &rounded, &inputs, radius);
+            print_params("refined f32", &refined, error);
+        }
+    }
+}
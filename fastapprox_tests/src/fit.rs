@@ -0,0 +1,308 @@
+//! Fitting of the constants of an approximation to its target function.
+//!
+//! A [`Model`] is a functional form with free parameters, such as `a + b / (c + x)`. Its
+//! parameters are fitted in double precision over a grid of points in an interval, either in the
+//! least-squares sense with Levenberg–Marquardt, or in the minimax sense with Lawson's
+//! iteratively reweighted least squares, which converges to the equioscillating solution of the
+//! Remez algorithm but also copes with parameters that enter the form nonlinearly.
+//!
+//! The fitted constants are then rounded to `f32`, which changes the error of the actual
+//! single-precision kernel. [`refine_f32`] re-optimizes them by searching neighbouring floats
+//! of each constant for a smaller maximum error of the kernel over a set of inputs.
+
+/// Measure of the error of an approximation `a` of the exact value `e`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Metric {
+    /// `a - e`.
+    Absolute,
+    /// `(a - e) / |e|`.
+    Relative,
+    /// `a - e` in units in the last place of `e` in single precision.
+    Ulp,
+}
+
+impl Metric {
+    /// Signed error of `actual` with respect to `exact`.
+    pub fn error(self, actual: f64, exact: f64) -> f64 {
+        match self {
+            Metric::Absolute => actual - exact,
+            Metric::Relative => (actual - exact) / exact.abs(),
+            Metric::Ulp => {
+                let exponent = exact.abs().max(f32::MIN_POSITIVE as f64).log2().floor();
+                (actual - exact) / (exponent - 23.0).exp2()
+            }
+        }
+    }
+}
+
+/// Functional form of an approximation with free parameters.
+#[derive(Clone, Copy)]
+pub struct Model {
+    /// Value of the form with the given parameters at `x`, in double precision.
+    pub eval: fn(&[f64], f64) -> f64,
+    /// Function approximated by the form.
+    pub target: fn(f64) -> f64,
+    /// Interval of `x` over which the form is fitted.
+    pub interval: (f64, f64),
+    /// Error measure to minimize.
+    pub metric: Metric,
+}
+
+/// Parameters found by a fit and the maximum error they reach.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fit {
+    /// Fitted parameters.
+    pub params: Vec<f64>,
+    /// Maximum absolute value of the error over the grid.
+    pub max_error: f64,
+    /// Point of the grid where the maximum error occurs.
+    pub at: f64,
+}
+
+impl Model {
+    /// `points` Chebyshev nodes of the second kind over the interval, which include its ends
+    /// and cluster near them, where the error of a minimax fit tends to peak.
+    pub fn grid(&self, points: usize) -> Vec<f64> {
+        let (a, b) = self.interval;
+        let n = points.max(2) - 1;
+        (0..=n)
+            .map(|i| {
+                let t = (std::f64::consts::PI * i as f64 / n as f64).cos();
+                0.5 * (a + b) - 0.5 * (b - a) * t
+            })
+            .collect()
+    }
+
+    /// Grid points paired with the target values there.
+    fn samples(&self, grid: &[f64]) -> Vec<(f64, f64)> {
+        grid.iter().map(|&x| (x, (self.target)(x))).collect()
+    }
+
+    /// Signed errors of the form with `params` at every sample.
+    fn errors(&self, params: &[f64], samples: &[(f64, f64)]) -> Vec<f64> {
+        samples
+            .iter()
+            .map(|&(x, exact)| self.metric.error((self.eval)(params, x), exact))
+            .collect()
+    }
+
+    /// Maximum error of the form with `params` over `grid`.
+    pub fn max_error(&self, params: &[f64], grid: &[f64]) -> Fit {
+        self.max_sample_error(params, &self.samples(grid))
+    }
+
+    fn max_sample_error(&self, params: &[f64], samples: &[(f64, f64)]) -> Fit {
+        let (at, max_error) = samples
+            .iter()
+            .zip(self.errors(params, samples))
+            .map(|(&(x, _), e)| (x, e.abs()))
+            .fold((f64::NAN, 0.0), |m, p| if p.1 > m.1 { p } else { m });
+        Fit {
+            params: params.to_vec(),
+            max_error,
+            at,
+        }
+    }
+
+    /// Least-squares fit over `points` grid points, starting from `start`.
+    pub fn least_squares(&self, start: &[f64], points: usize) -> Fit {
+        let samples = self.samples(&self.grid(points));
+        let weights = vec![1.0; samples.len()];
+        let params = self.weighted_least_squares(start, &samples, &weights);
+        self.max_sample_error(&params, &samples)
+    }
+
+    /// Minimax fit over `points` grid points, starting from `start`.
+    ///
+    /// Runs `iterations` rounds of Lawson's algorithm and returns the parameters with the
+    /// smallest maximum error seen.
+    pub fn minimax(&self, start: &[f64], points: usize, iterations: usize) -> Fit {
+        let samples = self.samples(&self.grid(points));
+        let mut weights = vec![1.0 / samples.len() as f64; samples.len()];
+        let mut params = start.to_vec();
+        let mut best = self.max_sample_error(&params, &samples);
+        for _ in 0..iterations {
+            params = self.weighted_least_squares(&params, &samples, &weights);
+            let errors = self.errors(&params, &samples);
+            let fit = self.max_sample_error(&params, &samples);
+            if fit.max_error < best.max_error {
+                best = fit;
+            }
+            let total: f64 = weights.iter().zip(&errors).map(|(w, e)| w * e.abs()).sum();
+            if total.is_nan() || total <= 0.0 {
+                break;
+            }
+            for (w, e) in weights.iter_mut().zip(&errors) {
+                *w *= e.abs() / total;
+            }
+        }
+        best
+    }
+
+    /// Levenberg–Marquardt minimization of the weighted sum of squared errors.
+    fn weighted_least_squares(
+        &self,
+        start: &[f64],
+        samples: &[(f64, f64)],
+        weights: &[f64],
+    ) -> Vec<f64> {
+        let n = start.len();
+        let cost = |p: &[f64]| -> f64 {
+            let errors = self.errors(p, samples);
+            weights.iter().zip(&errors).map(|(w, e)| w * e * e).sum()
+        };
+        let mut params = start.to_vec();
+        let mut current = cost(&params);
+        let mut lambda = 1e-3;
+        for _ in 0..100 {
+            let errors = self.errors(&params, samples);
+            let jacobian = self.jacobian(&params, samples);
+            // Normal equations JᵀWJ δ = -JᵀWe.
+            let mut jtj = vec![vec![0.0; n]; n];
+            let mut jte = vec![0.0; n];
+            for (i, row) in jacobian.iter().enumerate() {
+                for j in 0..n {
+                    jte[j] -= weights[i] * row[j] * errors[i];
+                    for k in 0..n {
+                        jtj[j][k] += weights[i] * row[j] * row[k];
+                    }
+                }
+            }
+            let mut improved = false;
+            while lambda < 1e12 {
+                let mut damped = jtj.clone();
+                for (j, row) in damped.iter_mut().enumerate() {
+                    row[j] += lambda * jtj[j][j].max(1e-300);
+                }
+                let step = match solve(damped, jte.clone()) {
+                    Some(step) => step,
+                    None => {
+                        lambda *= 10.0;
+                        continue;
+                    }
+                };
+                let trial: Vec<f64> = params.iter().zip(&step).map(|(p, s)| p + s).collect();
+                let c = cost(&trial);
+                if c < current {
+                    let converged = current - c <= 1e-15 * current;
+                    params = trial;
+                    current = c;
+                    lambda = (lambda * 0.1).max(1e-12);
+                    improved = !converged;
+                    break;
+                }
+                lambda *= 10.0;
+            }
+            if !improved {
+                break;
+            }
+        }
+        params
+    }
+
+    /// Central-difference derivatives of the errors with respect to the parameters.
+    fn jacobian(&self, params: &[f64], samples: &[(f64, f64)]) -> Vec<Vec<f64>> {
+        let mut rows = vec![vec![0.0; params.len()]; samples.len()];
+        let mut p = params.to_vec();
+        for j in 0..params.len() {
+            let h = 1e-7 * params[j].abs().max(1e-3);
+            p[j] = params[j] + h;
+            let plus = self.errors(&p, samples);
+            p[j] = params[j] - h;
+            let minus = self.errors(&p, samples);
+            p[j] = params[j];
+            for (row, (a, b)) in rows.iter_mut().zip(plus.iter().zip(&minus)) {
+                row[j] = (a - b) / (2.0 * h);
+            }
+        }
+        rows
+    }
+}
+
+/// Solves the linear system `a x = b` by Gaussian elimination with partial pivoting, or returns
+/// `None` if it is singular.
+pub fn solve(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Option<Vec<f64>> {
+    let n = b.len();
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+        if a[pivot][col] == 0.0 || a[pivot][col].is_nan() {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        let (top, bottom) = a.split_at_mut(col + 1);
+        let pivot_row = &top[col];
+        for (row, lower) in bottom.iter_mut().enumerate() {
+            let factor = lower[col] / pivot_row[col];
+            for (l, p) in lower[col..].iter_mut().zip(&pivot_row[col..]) {
+                *l -= factor * p;
+            }
+            b[col + 1 + row] -= factor * b[col];
+        }
+    }
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let sum: f64 = (row + 1..n).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - sum) / a[row][row];
+    }
+    Some(x)
+}
+
+/// Single-precision kernel whose constants are being tuned, with its reference.
+#[derive(Clone, Copy)]
+pub struct Kernel {
+    /// The kernel evaluated with the given constants.
+    pub eval: fn(&[f32], f32) -> f32,
+    /// Exact value of the function the kernel approximates.
+    pub reference: fn(f64) -> f64,
+    /// Error measure to minimize.
+    pub metric: Metric,
+}
+
+impl Kernel {
+    /// Maximum error of the kernel with `params` over `inputs`, and the input where it occurs.
+    pub fn max_error(&self, params: &[f32], inputs: &[f32]) -> (f64, f32) {
+        inputs
+            .iter()
+            .map(|&x| {
+                let actual = (self.eval)(params, x) as f64;
+                let e = self.metric.error(actual, (self.reference)(x as f64)).abs();
+                (if e.is_nan() { f64::INFINITY } else { e }, x)
+            })
+            .fold((0.0, f32::NAN), |m, p| if p.0 > m.0 { p } else { m })
+    }
+}
+
+/// Re-optimizes single-precision constants for the maximum error of `kernel` over `inputs`.
+///
+/// Starting from `params`, repeatedly moves each constant to a neighbouring float, up to
+/// `radius` units in the last place away, as long as that lowers the maximum error. Returns
+/// the constants found and their maximum error.
+pub fn refine_f32(kernel: &Kernel, params: &[f32], inputs: &[f32], radius: u32) -> (Vec<f32>, f64) {
+    let mut best = params.to_vec();
+    let mut best_error = kernel.max_error(&best, inputs).0;
+    loop {
+        let mut improved = false;
+        for j in 0..best.len() {
+            // Try steps of decreasing size in both directions.
+            let mut step = radius.max(1);
+            while step > 0 {
+                for direction in [1_i64, -1] {
+                    let mut trial = best.clone();
+                    let bits = best[j].to_bits() as i64 + direction * step as i64;
+                    trial[j] = f32::from_bits(bits as u32);
+                    let error = kernel.max_error(&trial, inputs).0;
+                    if error < best_error {
+                        best = trial;
+                        best_error = error;
+                        improved = true;
+                    }
+                }
+                step /= 2;
+            }
+        }
+        if !improved {
+            return (best, best_error);
+        }
+    }
+}
@@ -0,0 +1,401 @@
+//! Functional forms of the approximations whose constants come from the C original, or were
+//! fitted for this crate, set up for re-deriving those constants with [`crate::fit`].
+//!
+//! Each form is fitted in terms of the argument its kernel evaluates the polynomial or rational
+//! function at:
+//!
+//! - the mantissa left by the bit-level range reduction for `log2`,
+//! - the fractional part of the exponent for `pow2`,
+//! - `x` itself for `erfc` and the series of `exp_int_ei`,
+//! - `t = 1 / (1 + x / 2)` for `erfcx`,
+//! - `d = x - x₀` around the root `x₀` of `exp_int_ei`,
+//! - `u = 1 / x` for the asymptotic form of `exp_int_ei`.
+//!
+//! The kernels are copies of the ones in [`fastapprox::fast`] and [`fastapprox::faster`] that
+//! take their constants as parameters.
+
+use fastapprox::bits::{from_bits, to_bits};
+use fastapprox::{fast, faster, reference};
+
+use crate::fit::{Kernel, Metric, Model};
+
+/// An approximation with constants to fit.
+#[derive(Clone, Copy)]
+pub struct Form {
+    /// Name of the approximation, such as `fast::log2`.
+    pub name: &'static str,
+    /// The approximation with the constants written as `c0`, `c1`, ... .
+    pub formula: &'static str,
+    /// Constants of the C original, or of the crate for the forms fitted since.
+    pub original: &'static [f64],
+    /// Constants of the minimax fit of the model, for the forms whose original constants are
+    /// not a minimax point.
+    pub minimax: Option<&'static [f64]>,
+    /// Form fitted in double precision.
+    pub model: Model,
+    /// Single-precision kernel using the constants.
+    pub kernel: Kernel,
+    /// Interval of kernel inputs checked when re-optimizing for single precision.
+    pub inputs: (f32, f32),
+}
+
+impl Form {
+    /// Kernel inputs in the interval, every `stride`-th float.
+    pub fn inputs(&self, stride: u32) -> Vec<f32> {
+        let (lo, hi) = (self.inputs.0.to_bits(), self.inputs.1.to_bits());
+        assert!(
+            0.0 < self.inputs.0,
+            "only positive inputs are enumerated by bits"
+        );
+        (lo..=hi)
+            .step_by(stride.max(1) as usize)
+            .map(f32::from_bits)
+            .collect()
+    }
+}
+
+/// `fast::log2` for `x = 2^e (1 + f)` is `e + f + 127 - c0 - c1 m - c2 / (c3 + m)`, where
+/// `m = (1 + f) / 2` is the mantissa scaled into [1/2, 1).
+fn log2_model(c: &[f64], f: f64) -> f64 {
+    let m = 0.5 * (1.0 + f);
+    f + 127.0 - c[0] - c[1] * m - c[2] / (c[3] + m)
+}
+
+fn log2_target(f: f64) -> f64 {
+    f.ln_1p() / std::f64::consts::LN_2
+}
+
+#[allow(clippy::excessive_precision)]
+fn log2_kernel(c: &[f32], x: f32) -> f32 {
+    let vx = to_bits(x);
+    let mx = from_bits((vx & 0x007FFFFF_u32) | 0x3f000000);
+    let mut y = vx as f32;
+    y *= 1.1920928955078125e-7_f32;
+    y - c[0] - c[1] * mx - c[2] / (c[3] + mx)
+}
+
+/// `fast::pow2` for `p = w + z` sets the bits of the result to `2^23 (p + c0 + c1 / (c2 - z)
+/// - c3 z)`, which is `2^w (1 + t)` with `t = z + c0 - 127 + c1 / (c2 - z) - c3 z` as long as
+/// `t` stays in [0, 1).
+fn pow2_model(c: &[f64], z: f64) -> f64 {
+    1.0 + z + c[0] - 127.0 + c[1] / (c[2] - z) - c[3] * z
+}
+
+fn pow2_kernel(c: &[f32], p: f32) -> f32 {
+    let offset = if p < 0.0 { 1.0_f32 } else { 0.0_f32 };
+    let clipp = if p < -126.0 { -126.0_f32 } else { p };
+    let w = clipp as i32;
+    let z = clipp - (w as f32) + offset;
+    let v = ((1 << 23) as f32 * (clipp + c[0] + c[1] / (c[2] - z) - c[3] * z)) as u32;
+    from_bits(v)
+}
+
+/// `fast::erfc` is `2 / (1 + 2^(c0 x)) - c1 x (c2 x⁴ - 1) 2^(-c3 |x|)`, which satisfies
+/// `erfc(-x) = 2 - erfc(x)` for any constants, so that it is fitted for positive `x` only.
+///
+/// The C constants are not a minimax point of this form, for the absolute or the relative
+/// error over any interval `[0, b]`. Over `[0, 4]` the minimax fit moves weight from `c1 x` to
+/// the `x⁵` term and lowers the maximum error from 2.7e-3 to 2.6e-4, or to 1.1e-3 for the
+/// kernel refined in single precision, whose `pow2` approximations dominate. `fast::erfc` keeps
+/// the C constants so as to match `fasterfc` bit for bit.
+fn erfc_model(c: &[f64], x: f64) -> f64 {
+    2.0 / (1.0 + (c[0] * x).exp2()) - c[1] * x * (c[2] * x.powi(4) - 1.0) * (-c[3] * x.abs()).exp2()
+}
+
+fn erfc_kernel(c: &[f32], x: f32) -> f32 {
+    let mut v = to_bits(c[3] * x);
+    let xsq = x * x;
+    let xquad = xsq * xsq;
+
+    v |= 0x80000000;
+
+    2.0_f32 / (1.0_f32 + fast::pow2(c[0] * x))
+        - c[1] * x * (c[2] * xquad - 1.0_f32) * faster::pow2(from_bits(v))
+}
+
+/// `faster::erfcx` for `x ≥ 0` is `t exp(c0 + c1 t + c2 t² + c3 t³)` with
+/// `t = 1 / (1 + x / 2)`, fitted over `t` in (0, 1] for the relative error. As `t` goes to 0,
+/// `c0` goes to `-ln(2 √π)`.
+fn erfcx_model(c: &[f64], t: f64) -> f64 {
+    t * (c[0] + t * (c[1] + t * (c[2] + t * c[3]))).exp()
+}
+
+fn erfcx_target(t: f64) -> f64 {
+    reference::erfcx(2.0 / t - 2.0)
+}
+
+fn erfcx_kernel(c: &[f32], x: f32) -> f32 {
+    let t = 1.0_f32 / (1.0_f32 + 0.5_f32 * x);
+    t * faster::exp(c[0] + t * (c[1] + t * (c[2] + t * c[3])))
+}
+
+/// `exp_int_ei` below 6 is `γ + ln x + x P(x)`, the form of its power series with the
+/// coefficients `c0`, `c1`, ... of `P` fitted for the relative error.
+fn exp_int_ei_model(c: &[f64], x: f64) -> f64 {
+    reference::EULER + x.ln() + x * c.iter().rev().fold(0.0, |acc, &k| k + x * acc)
+}
+
+fn exp_int_ei_fast_kernel(c: &[f32], x: f32) -> f32 {
+    0.5772157_f32 + fast::ln(x) + x * c.iter().rev().fold(0.0_f32, |acc, &k| k + x * acc)
+}
+
+fn exp_int_ei_faster_kernel(c: &[f32], x: f32) -> f32 {
+    0.5772157_f32 + faster::ln(x) + x * c.iter().rev().fold(0.0_f32, |acc, &k| k + x * acc)
+}
+
+/// Positive root of `exp_int_ei`.
+const EXP_INT_EI_ROOT: f64 = 0.3725074107813666;
+
+/// `exp_int_ei` around its root `x0` is `d Q(d)` with `d = x - x0`, the coefficients `c0`,
+/// `c1`, ... of `Q` fitted for the relative error.
+fn exp_int_ei_root_model(c: &[f64], x: f64) -> f64 {
+    let d = x - EXP_INT_EI_ROOT;
+    d * c.iter().rev().fold(0.0, |acc, &k| k + d * acc)
+}
+
+fn exp_int_ei_root_kernel(c: &[f32], x: f32) -> f32 {
+    let d = (x - 0.37250742_f32) - -1.23810935e-8_f32;
+    d * c.iter().rev().fold(0.0_f32, |acc, &k| k + d * acc)
+}
+
+/// `fast::exp_int_ei` from 6 up is `exp(x) u N(u) / D(u)` with `u = 1 / x`, a cubic `N` with
+/// coefficients `c0` to `c3` and a monic cubic `D` with the others, as in Abramowitz and Stegun
+/// 5.1.54.
+fn exp_int_ei_large_model(c: &[f64], x: f64) -> f64 {
+    let u = 1.0 / x;
+    let num = c[0] + u * (c[1] + u * (c[2] + u * c[3]));
+    let den = 1.0 + u * (c[4] + u * (c[5] + u * c[6]));
+    x.exp() * u * num / den
+}
+
+fn exp_int_ei_large_kernel(c: &[f32], x: f32) -> f32 {
+    let u = 1.0_f32 / x;
+    let num = c[0] + u * (c[1] + u * (c[2] + u * c[3]));
+    let den = 1.0_f32 + u * (c[4] + u * (c[5] + u * c[6]));
+    fast::exp(x) * u * num / den
+}
+
+/// `faster::exp_int_ei` from 6 up, like [`exp_int_ei_large_model`] with a linear `N` and `D`.
+fn exp_int_ei_faster_large_model(c: &[f64], x: f64) -> f64 {
+    let u = 1.0 / x;
+    x.exp() * u * (c[0] + c[1] * u) / (1.0 + c[2] * u)
+}
+
+fn exp_int_ei_faster_large_kernel(c: &[f32], x: f32) -> f32 {
+    let u = 1.0_f32 / x;
+    faster::exp(x) * u * (c[0] + c[1] * u) / (1.0_f32 + c[2] * u)
+}
+
+/// The forms that can be fitted.
+pub const FORMS: &[Form] = &[
+    Form {
+        name: "fast::log2",
+        formula: "bits(x) / 2^23 - c0 - c1 m - c2 / (c3 + m)",
+        original: &[124.22551499, 1.498030302, 1.72587999, 0.3520887068],
+        minimax: None,
+        model: Model {
+            eval: log2_model,
+            target: log2_target,
+            interval: (0.0, 1.0),
+            metric: Metric::Absolute,
+        },
+        kernel: Kernel {
+            eval: log2_kernel,
+            reference: f64::log2,
+            metric: Metric::Absolute,
+        },
+        inputs: (0.25, 4.0),
+    },
+    Form {
+        name: "fast::pow2",
+        formula: "2^23 (p + c0 + c1 / (c2 - z) - c3 z)",
+        original: &[121.2740575, 27.7280233, 4.84252568, 1.49012907],
+        minimax: None,
+        model: Model {
+            eval: pow2_model,
+            target: f64::exp2,
+            interval: (0.0, 1.0),
+            metric: Metric::Relative,
+        },
+        kernel: Kernel {
+            eval: pow2_kernel,
+            reference: f64::exp2,
+            metric: Metric::Relative,
+        },
+        inputs: (1e-3, 4.0),
+    },
+    Form {
+        name: "fast::erfc",
+        formula: "2 / (1 + 2^(c0 x)) - c1 x (c2 x^4 - 1) 2^(-c3 |x|)",
+        original: &[
+            3.3509633149424609,
+            0.07219054755431126,
+            15.418191568719577,
+            5.609846028328545,
+        ],
+        minimax: Some(&[
+            3.2724096534721947,
+            3.90993009559459e-3,
+            4.575269179350339e2,
+            5.872016358994297,
+        ]),
+        model: Model {
+            eval: erfc_model,
+            target: reference::erfc,
+            interval: (0.0, 4.0),
+            metric: Metric::Absolute,
+        },
+        kernel: Kernel {
+            eval: erfc_kernel,
+            reference: reference::erfc,
+            metric: Metric::Absolute,
+        },
+        inputs: (1e-3, 4.0),
+    },
+    Form {
+        name: "faster::erfcx",
+        formula: "t exp(c0 + c1 t + c2 t^2 + c3 t^3)",
+        original: &[-1.2644419, 0.96026525, 0.60171720, -0.29647146],
+        minimax: None,
+        model: Model {
+            eval: erfcx_model,
+            target: erfcx_target,
+            interval: (1e-6, 1.0),
+            metric: Metric::Relative,
+        },
+        kernel: Kernel {
+            eval: erfcx_kernel,
+            reference: reference::erfcx,
+            metric: Metric::Relative,
+        },
+        inputs: (1e-3, 1e30),
+    },
+    Form {
+        name: "fast::exp_int_ei",
+        formula: "γ + ln x + x (c0 + c1 x + ... + c7 x^7)",
+        original: &[
+            0.9991307,
+            0.255736,
+            0.04176851,
+            0.025929598,
+            -0.0074612694,
+            0.0031546766,
+            -0.0004650312,
+            3.9772465e-05,
+        ],
+        minimax: None,
+        model: Model {
+            eval: exp_int_ei_model,
+            target: reference::exp_int_ei,
+            interval: (1e-6, 6.0),
+            metric: Metric::Relative,
+        },
+        kernel: Kernel {
+            eval: exp_int_ei_fast_kernel,
+            reference: reference::exp_int_ei,
+            metric: Metric::Relative,
+        },
+        inputs: (0.50000006, 5.9999995),
+    },
+    Form {
+        name: "fast::exp_int_ei_root",
+        formula: "d (c0 + c1 d + ... + c6 d^6)",
+        original: &[
+            3.8962157, -3.2817721, 6.522805, -12.886721, 27.708103, -72.67686, 166.16518,
+        ],
+        minimax: None,
+        model: Model {
+            eval: exp_int_ei_root_model,
+            target: reference::exp_int_ei,
+            interval: (0.25, 0.5),
+            metric: Metric::Relative,
+        },
+        kernel: Kernel {
+            eval: exp_int_ei_root_kernel,
+            reference: reference::exp_int_ei,
+            metric: Metric::Relative,
+        },
+        inputs: (0.25, 0.5),
+    },
+    Form {
+        name: "fast::exp_int_ei_large",
+        formula: "exp(x) u (c0 + c1 u + c2 u^2 + c3 u^3) / (1 + c4 u + c5 u^2 + c6 u^3)",
+        original: &[
+            0.99988014, -11.07751, 44.80629, -20.112457, -12.090321, 55.28323, -60.91256,
+        ],
+        minimax: None,
+        model: Model {
+            eval: exp_int_ei_large_model,
+            target: reference::exp_int_ei,
+            interval: (6.0, 88.0),
+            metric: Metric::Relative,
+        },
+        kernel: Kernel {
+            eval: exp_int_ei_large_kernel,
+            reference: reference::exp_int_ei,
+            metric: Metric::Relative,
+        },
+        inputs: (6.0, 88.0),
+    },
+    Form {
+        name: "faster::exp_int_ei",
+        formula: "γ + ln x + x (c0 + c1 x + ... + c5 x^5)",
+        original: &[
+            0.98354155,
+            0.3306171,
+            -0.06843888,
+            0.088484466,
+            -0.020077167,
+            0.0026129233,
+        ],
+        minimax: None,
+        model: Model {
+            eval: exp_int_ei_model,
+            target: reference::exp_int_ei,
+            interval: (1e-6, 6.0),
+            metric: Metric::Relative,
+        },
+        kernel: Kernel {
+            eval: exp_int_ei_faster_kernel,
+            reference: reference::exp_int_ei,
+            metric: Metric::Relative,
+        },
+        inputs: (0.50000006, 5.9999995),
+    },
+    Form {
+        name: "faster::exp_int_ei_root",
+        formula: "d (c0 + c1 d + c2 d^2 + c3 d^3)",
+        original: &[3.895286, -3.2779267, 6.989554, -14.053596],
+        minimax: None,
+        model: Model {
+            eval: exp_int_ei_root_model,
+            target: reference::exp_int_ei,
+            interval: (0.25, 0.5),
+            metric: Metric::Relative,
+        },
+        kernel: Kernel {
+            eval: exp_int_ei_root_kernel,
+            reference: reference::exp_int_ei,
+            metric: Metric::Relative,
+        },
+        inputs: (0.25, 0.5),
+    },
+    Form {
+        name: "faster::exp_int_ei_large",
+        formula: "exp(x) u (c0 + c1 u) / (1 + c2 u)",
+        original: &[0.9977408, -1.3100598, -2.3499215],
+        minimax: None,
+        model: Model {
+            eval: exp_int_ei_faster_large_model,
+            target: reference::exp_int_ei,
+            interval: (6.0, 88.0),
+            metric: Metric::Relative,
+        },
+        kernel: Kernel {
+            eval: exp_int_ei_faster_large_kernel,
+            reference: reference::exp_int_ei,
+            metric: Metric::Relative,
+        },
+        inputs: (6.0, 88.0),
+    },
+];
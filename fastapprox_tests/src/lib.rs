@@ -0,0 +1,6 @@
+//! Accuracy sweeps of the `fastapprox` approximations against high-precision references.
+
+pub mod accuracy;
+pub mod fit;
+pub mod forms;
+pub mod report;
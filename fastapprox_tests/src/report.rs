@@ -0,0 +1,268 @@
+//! Machine-readable accuracy reports and the comparison against a baseline.
+//!
+//! A report lists the statistics of every interval of every swept function. It is written as
+//! CSV with one row per interval, or as a JSON array with one object per interval that also
+//! carries the ULP histogram. Floats are written in their shortest round-trip form, so that a
+//! report read back from CSV is identical to the one written, apart from the histograms.
+
+use std::fmt::Write;
+
+use fastapprox::registry::{BinaryInfo, FunctionInfo};
+
+use crate::accuracy::{Interval, Stats, Sweep};
+
+/// Stride of the sweep recorded in the checked-in baseline.
+pub const BASELINE_STRIDE: u64 = 1 << 16;
+
+/// Number of intervals per function in the checked-in baseline.
+pub const BASELINE_INTERVALS: usize = 4;
+
+/// Header row of the CSV format.
+pub const CSV_HEADER: &str = "function,tier,lo,hi,count,skipped,invalid,\
+                              max_ulp,max_ulp_x,max_rel,max_rel_x,max_abs,max_abs_x";
+
+/// Statistics of one interval of one function.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+    /// Name of the function, such as `ln`, or `pow@2.5` for a function of two arguments
+    /// swept over the first one with the second fixed at 2.5.
+    pub function: String,
+    /// Tier of the approximation, such as `fast`.
+    pub tier: String,
+    /// The interval and its statistics.
+    pub interval: Interval,
+}
+
+impl Record {
+    /// Qualified name of the function, such as `fast::ln`.
+    pub fn name(&self) -> String {
+        format!("{}::{}", self.tier, self.function)
+    }
+}
+
+/// Sweeps `function` and returns one record per interval.
+pub fn sweep(function: &FunctionInfo, sweep: &Sweep, intervals: usize) -> Vec<Record> {
+    let (lo, hi) = function.domain;
+    sweep
+        .run_intervals(function.approx, function.reference, lo, hi, intervals)
+        .into_iter()
+        .map(|interval| Record {
+            function: function.name.to_string(),
+            tier: function.tier.to_string(),
+            interval,
+        })
+        .collect()
+}
+
+/// Sweeps `function` over its first argument with the second fixed at `y`, and returns one
+/// record per interval.
+pub fn sweep_binary(function: &BinaryInfo, y: f32, sweep: &Sweep, intervals: usize) -> Vec<Record> {
+    let (lo, hi) = function.domain;
+    let approx = |x| (function.approx)(x, y);
+    let reference = |x| (function.reference)(x, y as f64);
+    sweep
+        .run_intervals(approx, reference, lo, hi, intervals)
+        .into_iter()
+        .map(|interval| Record {
+            function: format!("{}@{}", function.name, y),
+            tier: function.tier.to_string(),
+            interval,
+        })
+        .collect()
+}
+
+/// Writes `records` as CSV, including the header row.
+pub fn to_csv(records: &[Record]) -> String {
+    let mut out = String::new();
+    writeln!(out, "{}", CSV_HEADER).unwrap();
+    for r in records {
+        let s = &r.interval.stats;
+        writeln!(
+            out,
+            "{},{},{:e},{:e},{},{},{},{},{:e},{:e},{:e},{:e},{:e}",
+            r.function,
+            r.tier,
+            r.interval.lo,
+            r.interval.hi,
+            s.count,
+            s.skipped,
+            s.invalid,
+            s.max_ulp.0,
+            s.max_ulp.1,
+            s.max_rel.0,
+            s.max_rel.1,
+            s.max_abs.0,
+            s.max_abs.1
+        )
+        .unwrap();
+    }
+    out
+}
+
+/// JSON number, or `null` for the NaN of an empty interval, which JSON cannot represent.
+fn json_input(x: f32) -> String {
+    if x.is_nan() {
+        "null".to_string()
+    } else {
+        format!("{:e}", x)
+    }
+}
+
+/// Writes `records` as a JSON array of objects.
+pub fn to_json(records: &[Record]) -> String {
+    let mut out = String::from("[");
+    for (i, r) in records.iter().enumerate() {
+        let s = &r.interval.stats;
+        let histogram: Vec<String> = s.histogram.iter().map(u64::to_string).collect();
+        out.push_str(if i == 0 { "\n" } else { ",\n" });
+        write!(
+            out,
+            "  {{\"function\": \"{}\", \"tier\": \"{}\", \"lo\": {:e}, \"hi\": {:e}, \
+             \"count\": {}, \"skipped\": {}, \"invalid\": {}, \
+             \"max_ulp\": {}, \"max_ulp_x\": {}, \"max_rel\": {:e}, \"max_rel_x\": {}, \
+             \"max_abs\": {:e}, \"max_abs_x\": {}, \"histogram\": [{}]}}",
+            r.function,
+            r.tier,
+            r.interval.lo,
+            r.interval.hi,
+            s.count,
+            s.skipped,
+            s.invalid,
+            s.max_ulp.0,
+            json_input(s.max_ulp.1),
+            s.max_rel.0,
+            json_input(s.max_rel.1),
+            s.max_abs.0,
+            json_input(s.max_abs.1),
+            histogram.join(", ")
+        )
+        .unwrap();
+    }
+    out.push_str("\n]\n");
+    out
+}
+
+/// Reads records written by [`to_csv`].
+///
+/// Histograms are not part of the CSV format and are left empty.
+pub fn from_csv(csv: &str) -> Result<Vec<Record>, String> {
+    let mut lines = csv.lines().enumerate();
+    match lines.next() {
+        Some((_, header)) if header.trim() == CSV_HEADER => {}
+        _ => return Err("missing or unexpected CSV header".to_string()),
+    }
+    lines
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(n, line)| parse_row(line).map_err(|e| format!("line {}: {}", n + 1, e)))
+        .collect()
+}
+
+fn parse_row(line: &str) -> Result<Record, String> {
+    let fields: Vec<&str> = line.trim().split(',').collect();
+    if fields.len() != 13 {
+        return Err(format!("expected 13 fields, found {}", fields.len()));
+    }
+    fn parse<T: std::str::FromStr>(field: &str) -> Result<T, String> {
+        field
+            .parse()
+            .map_err(|_| format!("invalid field {:?}", field))
+    }
+    Ok(Record {
+        function: fields[0].to_string(),
+        tier: fields[1].to_string(),
+        interval: Interval {
+            lo: parse(fields[2])?,
+            hi: parse(fields[3])?,
+            stats: Stats {
+                count: parse(fields[4])?,
+                skipped: parse(fields[5])?,
+                invalid: parse(fields[6])?,
+                max_ulp: (parse(fields[7])?, parse(fields[8])?),
+                max_rel: (parse(fields[9])?, parse(fields[10])?),
+                max_abs: (parse(fields[11])?, parse(fields[12])?),
+                ..Stats::default()
+            },
+        },
+    })
+}
+
+/// Whether `current` exceeds `baseline` by more than a relative slack of 0.1%, which absorbs
+/// last-bit differences of the double-precision references between platforms.
+fn exceeds(current: f64, baseline: f64) -> bool {
+    current > baseline * 1.001 + f64::MIN_POSITIVE
+}
+
+/// Compares `current` against `baseline` and describes every regression.
+///
+/// A record regresses if it has more invalid results or a larger maximum ULP, relative or
+/// absolute error than its baseline. Records whose interval or number of points differ from
+/// the baseline, and records missing from it, are reported too, since they cannot be compared.
+pub fn regressions(baseline: &[Record], current: &[Record]) -> Vec<String> {
+    let mut problems = Vec::new();
+    for (name, group) in group_by_function(current) {
+        let base: Vec<&Record> = baseline.iter().filter(|r| r.name() == name).collect();
+        if base.is_empty() {
+            problems.push(format!("{}: missing from the baseline", name));
+            continue;
+        }
+        if base.len() != group.len() {
+            problems.push(format!(
+                "{}: {} intervals, but {} in the baseline",
+                name,
+                group.len(),
+                base.len()
+            ));
+            continue;
+        }
+        for (b, c) in base.iter().zip(&group) {
+            let (bi, ci) = (&b.interval, &c.interval);
+            let at = format!("{} on [{:e}, {:e}]", name, ci.lo, ci.hi);
+            if (bi.lo, bi.hi, bi.stats.count) != (ci.lo, ci.hi, ci.stats.count) {
+                problems.push(format!(
+                    "{}: the baseline covers [{:e}, {:e}] with {} points instead of {}",
+                    at, bi.lo, bi.hi, bi.stats.count, ci.stats.count
+                ));
+                continue;
+            }
+            let (bs, cs) = (&bi.stats, &ci.stats);
+            if cs.invalid > bs.invalid {
+                problems.push(format!(
+                    "{}: {} invalid results, baseline {}",
+                    at, cs.invalid, bs.invalid
+                ));
+            }
+            if exceeds(cs.max_ulp.0 as f64, bs.max_ulp.0 as f64 + 1.0) {
+                problems.push(format!(
+                    "{}: max ulp {} at x = {:e}, baseline {}",
+                    at, cs.max_ulp.0, cs.max_ulp.1, bs.max_ulp.0
+                ));
+            }
+            if exceeds(cs.max_rel.0, bs.max_rel.0) {
+                problems.push(format!(
+                    "{}: max rel {:e} at x = {:e}, baseline {:e}",
+                    at, cs.max_rel.0, cs.max_rel.1, bs.max_rel.0
+                ));
+            }
+            if exceeds(cs.max_abs.0, bs.max_abs.0) {
+                problems.push(format!(
+                    "{}: max abs {:e} at x = {:e}, baseline {:e}",
+                    at, cs.max_abs.0, cs.max_abs.1, bs.max_abs.0
+                ));
+            }
+        }
+    }
+    problems
+}
+
+/// Groups consecutive records of the same function.
+fn group_by_function(records: &[Record]) -> Vec<(String, Vec<&Record>)> {
+    let mut groups: Vec<(String, Vec<&Record>)> = Vec::new();
+    for r in records {
+        let name = r.name();
+        match groups.last_mut() {
+            Some((last, group)) if *last == name => group.push(r),
+            _ => groups.push((name, vec![r])),
+        }
+    }
+    groups
+}
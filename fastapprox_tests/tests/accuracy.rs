@@ -0,0 +1,107 @@
+#![cfg(test)]
+
+extern crate fastapprox;
+extern crate fastapprox_tests;
+
+use fastapprox::fast;
+use fastapprox_tests::accuracy::{
+    from_ordinal, histogram_bin, ordinal, ulp_distance, Stats, Sweep, HISTOGRAM_BINS,
+};
+
+#[test]
+fn test_ordinal() {
+    assert_eq!(ordinal(0.0), 0);
+    assert_eq!(ordinal(-0.0), 0);
+    assert_eq!(ordinal(f32::from_bits(1)), 1);
+    assert_eq!(ordinal(-f32::from_bits(1)), -1);
+    assert!(ordinal(-1.0) < ordinal(-0.5));
+    assert!(ordinal(0.5) < ordinal(1.0));
+    for &x in &[-f32::MAX, -1.5, -1e-40, 0.0, 1e-40, 3.0, f32::MAX] {
+        assert_eq!(from_ordinal(ordinal(x)), x);
+    }
+}
+
+#[test]
+fn test_ulp_distance() {
+    assert_eq!(ulp_distance(1.0, 1.0), 0);
+    assert_eq!(ulp_distance(1.0, f32::from_bits(1.0_f32.to_bits() + 3)), 3);
+    assert_eq!(ulp_distance(-f32::from_bits(1), f32::from_bits(1)), 2);
+    assert_eq!(ulp_distance(0.0, -0.0), 0);
+}
+
+#[test]
+fn test_histogram_bin() {
+    assert_eq!(histogram_bin(0), 0);
+    assert_eq!(histogram_bin(1), 1);
+    assert_eq!(histogram_bin(2), 2);
+    assert_eq!(histogram_bin(3), 2);
+    assert_eq!(histogram_bin(4), 3);
+    assert_eq!(histogram_bin(u64::MAX), HISTOGRAM_BINS - 1);
+}
+
+#[test]
+fn test_stats() {
+    let mut stats = Stats::default();
+    stats.record(1.0, 2.0, 2.0);
+    stats.record(2.0, f32::from_bits(4.0_f32.to_bits() + 2), 4.0);
+    stats.record(3.0, f32::NAN, 8.0);
+    stats.record(4.0, 1.0, f64::INFINITY);
+    assert_eq!(stats.count, 3);
+    assert_eq!(stats.skipped, 1);
+    assert_eq!(stats.invalid, 1);
+    assert_eq!(stats.max_ulp, (2, 2.0));
+    assert_eq!(stats.histogram[0], 1);
+    assert_eq!(stats.histogram[2], 1);
+
+    let mut merged = Stats::default();
+    merged.merge(&stats);
+    merged.merge(&Stats::default());
+    assert_eq!(merged, stats);
+}
+
+#[test]
+fn test_sweep_exact() {
+    let sweep = Sweep {
+        stride: 1,
+        threads: 3,
+    };
+    let stats = sweep.run(|x| x, |x| x, 1.0, 2.0);
+    assert_eq!(stats.count, (1 << 23) + 1);
+    assert_eq!(stats.max_ulp.0, 0);
+    assert_eq!(stats.histogram[0], stats.count);
+}
+
+#[test]
+fn test_sweep_ln() {
+    let sweep = Sweep {
+        stride: 1 << 16,
+        threads: 2,
+    };
+    let stats = sweep.run(fast::ln, f64::ln, 0.5, 1e30);
+    assert_eq!(stats.invalid, 0);
+    assert!(stats.max_abs.0 < 2e-4, "{:?}", stats);
+}
+
+#[test]
+fn test_sweep_intervals() {
+    let sweep = Sweep {
+        stride: 3,
+        threads: 2,
+    };
+    let intervals = sweep.run_intervals(fast::ln, f64::ln, 1.0, 2.0, 5);
+    assert_eq!(intervals.len(), 5);
+    assert_eq!(intervals[0].lo, 1.0);
+    for w in intervals.windows(2) {
+        assert_eq!(ulp_distance(w[0].hi, w[1].lo), 3);
+    }
+    let counts: Vec<u64> = intervals.iter().map(|i| i.stats.count).collect();
+    assert_eq!(counts.iter().sum::<u64>(), (1 << 23) / 3 + 1);
+    assert!(counts.iter().max().unwrap() - counts.iter().min().unwrap() <= 1);
+
+    let mut merged = Stats::default();
+    for i in &intervals {
+        merged.merge(&i.stats);
+    }
+    assert_eq!(merged, sweep.run(fast::ln, f64::ln, 1.0, 2.0));
+    assert_eq!(sweep.run_intervals(|x| x, |x| x, 1.0, 1.0, 4).len(), 1);
+}
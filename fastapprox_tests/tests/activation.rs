@@ -0,0 +1,267 @@
+#![cfg(test)]
+
+extern crate fastapprox;
+extern crate special;
+
+use fastapprox::activation::{fast, faster};
+use special::Error;
+
+mod common;
+
+use common::{assert_all_near, assert_near};
+
+const FLOATS: &[f32] = &[
+    -1000.0, -100.0, -20.0, -5.0, -1.0, -0.25, -0.05, 0.0, 0.05, 1.0, 2.0, 3.0, 10.0, 100.0, 1000.0,
+];
+
+const NEAR: f32 = 0.01;
+const FAR: f32 = 0.15;
+
+fn sigmoid(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+fn softplus(x: f64) -> f64 {
+    x.max(0.0) + (-x.abs()).exp().ln_1p()
+}
+
+fn elu(x: f64, alpha: f64) -> f64 {
+    if x > 0.0 {
+        x
+    } else {
+        alpha * x.exp_m1()
+    }
+}
+
+fn check<F1, F2, F3>(fast: F1, faster: F2, exact: F3)
+where
+    F1: Fn(f32) -> f32,
+    F2: Fn(f32) -> f32,
+    F3: Fn(f64) -> f64,
+{
+    for &x in FLOATS {
+        let e = exact(x as f64) as f32;
+        assert_near(fast(x), e, NEAR);
+        assert_near(faster(x), e, FAR);
+    }
+}
+
+/// Checks the derivative against a central finite difference of the exact function.
+fn check_grad<F1, F2, F3, F4>(fast: F1, faster: F2, value: F3, exact: F4)
+where
+    F1: Fn(f32) -> (f32, f32),
+    F2: Fn(f32) -> (f32, f32),
+    F3: Fn(f64) -> f64,
+    F4: Fn(f64) -> f64,
+{
+    const H: f64 = 1e-6;
+
+    // ELU and SELU have a kink at zero.
+    for &x in FLOATS.iter().filter(|&&x| x != 0.0) {
+        let x64 = x as f64;
+        let v = value(x64) as f32;
+        let g = ((exact(x64 + H) - exact(x64 - H)) / (2.0 * H)) as f32;
+        let (fv, fg) = fast(x);
+        let (fvv, fgg) = faster(x);
+        assert_near(fv, v, NEAR);
+        assert_near(fg, g, NEAR);
+        assert_near(fvv, v, FAR);
+        assert_near(fgg, g, FAR);
+    }
+}
+
+fn check_slice<F1, F2, F3>(fast: F1, faster: F2, exact: F3)
+where
+    F1: Fn(&[f32], &mut [f32]),
+    F2: Fn(&[f32], &mut [f32]),
+    F3: Fn(f64) -> f64,
+{
+    let expected: Vec<f32> = FLOATS.iter().map(|&x| exact(x as f64) as f32).collect();
+    let mut out = vec![0.0; FLOATS.len()];
+    fast(FLOATS, &mut out);
+    assert_all_near(&out, &expected, NEAR);
+    faster(FLOATS, &mut out);
+    assert_all_near(&out, &expected, FAR);
+}
+
+#[test]
+fn test_softplus() {
+    check(fast::softplus, faster::softplus, softplus);
+    check_slice(fast::softplus_slice, faster::softplus_slice, softplus);
+}
+
+#[test]
+fn test_gelu() {
+    let exact = |x: f64| 0.5 * x * (1.0 + (x / 2.0_f64.sqrt()).error());
+    check(fast::gelu, faster::gelu, exact);
+    check_slice(fast::gelu_slice, faster::gelu_slice, exact);
+}
+
+#[test]
+fn test_gelu_tanh() {
+    let exact = |x: f64| {
+        let p = (2.0 / std::f64::consts::PI).sqrt() * (x + 0.044715 * x * x * x);
+        0.5 * x * (1.0 + p.tanh())
+    };
+    check(fast::gelu_tanh, faster::gelu_tanh, exact);
+    check_slice(fast::gelu_tanh_slice, faster::gelu_tanh_slice, exact);
+}
+
+#[test]
+fn test_silu() {
+    let exact = |x: f64| x * sigmoid(x);
+    check(fast::silu, faster::silu, exact);
+    check_slice(fast::silu_slice, faster::silu_slice, exact);
+}
+
+#[test]
+fn test_swish() {
+    for &beta in &[0.5_f32, 1.0, 1.702] {
+        let exact = |x: f64| x * sigmoid(beta as f64 * x);
+        check(|x| fast::swish(x, beta), |x| faster::swish(x, beta), exact);
+        check_slice(
+            |xs, out| fast::swish_slice(xs, beta, out),
+            |xs, out| faster::swish_slice(xs, beta, out),
+            exact,
+        );
+    }
+}
+
+#[test]
+fn test_mish() {
+    let exact = |x: f64| x * softplus(x).tanh();
+    check(fast::mish, faster::mish, exact);
+    check_slice(fast::mish_slice, faster::mish_slice, exact);
+}
+
+#[test]
+fn test_elu() {
+    for &alpha in &[0.1_f32, 1.0, 2.0] {
+        let exact = |x: f64| elu(x, alpha as f64);
+        check(|x| fast::elu(x, alpha), |x| faster::elu(x, alpha), exact);
+        check_slice(
+            |xs, out| fast::elu_slice(xs, alpha, out),
+            |xs, out| faster::elu_slice(xs, alpha, out),
+            exact,
+        );
+    }
+}
+
+#[test]
+fn test_selu() {
+    let exact = |x: f64| 1.0507009873554805 * elu(x, 1.6732632423543772);
+    check(fast::selu, faster::selu, exact);
+    check_slice(fast::selu_slice, faster::selu_slice, exact);
+}
+
+#[test]
+fn test_softsign() {
+    let exact = |x: f64| x / (1.0 + x.abs());
+    check(fast::softsign, faster::softsign, exact);
+    check_slice(fast::softsign_slice, faster::softsign_slice, exact);
+}
+
+#[test]
+fn test_sigmoid_with_grad() {
+    check_grad(
+        fast::sigmoid_with_grad,
+        faster::sigmoid_with_grad,
+        sigmoid,
+        sigmoid,
+    );
+}
+
+#[test]
+fn test_tanh_with_grad() {
+    check_grad(
+        fast::tanh_with_grad,
+        faster::tanh_with_grad,
+        f64::tanh,
+        f64::tanh,
+    );
+}
+
+#[test]
+fn test_softplus_with_grad() {
+    check_grad(
+        fast::softplus_with_grad,
+        faster::softplus_with_grad,
+        softplus,
+        softplus,
+    );
+}
+
+#[test]
+fn test_gelu_with_grad() {
+    let exact = |x: f64| 0.5 * x * (1.0 + (x / 2.0_f64.sqrt()).error());
+    check_grad(fast::gelu_with_grad, faster::gelu_with_grad, exact, exact);
+}
+
+#[test]
+fn test_gelu_tanh_with_grad() {
+    let exact = |x: f64| {
+        let p = (2.0 / std::f64::consts::PI).sqrt() * (x + 0.044715 * x * x * x);
+        0.5 * x * (1.0 + p.tanh())
+    };
+    check_grad(
+        fast::gelu_tanh_with_grad,
+        faster::gelu_tanh_with_grad,
+        exact,
+        exact,
+    );
+}
+
+#[test]
+fn test_silu_with_grad() {
+    let exact = |x: f64| x * sigmoid(x);
+    check_grad(fast::silu_with_grad, faster::silu_with_grad, exact, exact);
+}
+
+#[test]
+fn test_swish_with_grad() {
+    for &beta in &[0.5_f32, 1.0, 1.702] {
+        let exact = |x: f64| x * sigmoid(beta as f64 * x);
+        check_grad(
+            |x| fast::swish_with_grad(x, beta),
+            |x| faster::swish_with_grad(x, beta),
+            exact,
+            exact,
+        );
+    }
+}
+
+#[test]
+fn test_mish_with_grad() {
+    let exact = |x: f64| x * softplus(x).tanh();
+    check_grad(fast::mish_with_grad, faster::mish_with_grad, exact, exact);
+}
+
+#[test]
+fn test_elu_with_grad() {
+    for &alpha in &[0.1_f32, 1.0, 2.0] {
+        let exact = |x: f64| elu(x, alpha as f64);
+        check_grad(
+            |x| fast::elu_with_grad(x, alpha),
+            |x| faster::elu_with_grad(x, alpha),
+            exact,
+            exact,
+        );
+    }
+}
+
+#[test]
+fn test_selu_with_grad() {
+    let exact = |x: f64| 1.0507009873554805 * elu(x, 1.6732632423543772);
+    check_grad(fast::selu_with_grad, faster::selu_with_grad, exact, exact);
+}
+
+#[test]
+fn test_softsign_with_grad() {
+    let exact = |x: f64| x / (1.0 + x.abs());
+    check_grad(
+        fast::softsign_with_grad,
+        faster::softsign_with_grad,
+        exact,
+        exact,
+    );
+}
@@ -3,7 +3,9 @@ macro_rules! extern_unsafe_wraps {
         $( $fn:ident, )*
     ) => {
         mod cc {
-            extern {
+            // Compiled by the build script, which only links it into the library target.
+            #[link(name = "fastapprox", kind = "static")]
+            extern "C" {
                 $(
                     pub fn $fn(x: f32) -> f32;
                 )*
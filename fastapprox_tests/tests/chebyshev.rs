@@ -0,0 +1,103 @@
+#![cfg(test)]
+
+extern crate fastapprox;
+
+use fastapprox::chebyshev::Chebyshev;
+
+mod common;
+
+use common::assert_abs_near;
+
+/// `n` evenly spaced points of `[a, b]`, including both ends.
+fn points(a: f32, b: f32, n: usize) -> Vec<f32> {
+    (0..n)
+        .map(|i| a + (b - a) * i as f32 / (n - 1) as f32)
+        .collect()
+}
+
+#[test]
+fn test_polynomial() {
+    // x³ = (3 T_1 + T_3) / 4.
+    let p = Chebyshev::<3>::fit(-1.0, 1.0, |x| x * x * x);
+    for (k, &expected) in [0.0, 0.75, 0.0, 0.25].iter().enumerate() {
+        assert_abs_near(p.coefficient(k), expected, 1e-6);
+    }
+    assert!(p.max_error() < 1e-6, "{}", p.max_error());
+
+    let q = Chebyshev::<5>::fit(1.0, 3.0, |x| 2.0 - x + 0.5 * x * x);
+    assert_eq!(q.domain(), (1.0, 3.0));
+    assert_abs_near(q.coefficient(3), 0.0, 1e-6);
+    for x in points(1.0, 3.0, 41) {
+        assert_abs_near(q.eval(x), 2.0 - x + 0.5 * x * x, 1e-5);
+    }
+}
+
+#[test]
+fn test_fit() {
+    let f = |x: f32| (x as f64).exp() as f32;
+    let p = Chebyshev::<12>::fit(0.0, 2.0, f);
+    assert!(p.max_error() < 2e-6, "{}", p.max_error());
+    for x in points(0.0, 2.0, 1001) {
+        assert_abs_near(p.eval(x), f(x), 2.0 * p.max_error() + 1e-6);
+    }
+
+    // A low degree leaves a visible error, which the estimate catches.
+    let q = Chebyshev::<4>::fit(0.0, 2.0, f);
+    let observed = points(0.0, 2.0, 1001)
+        .into_iter()
+        .map(|x| (q.eval(x) - f(x)).abs())
+        .fold(0.0_f32, f32::max);
+    assert!(observed > 1e-4);
+    assert!(q.max_error() > 0.9 * observed && q.max_error() < 1.1 * observed);
+
+    let mut calls = 0;
+    Chebyshev::<7>::fit(-1.0, 1.0, |x| {
+        calls += 1;
+        x
+    });
+    assert_eq!(calls, 2 * 7 + 3);
+}
+
+#[test]
+fn test_derivative() {
+    let p = Chebyshev::<16>::fit(-2.0, 3.0, |x| (x as f64).sin() as f32);
+    let d = p.derivative();
+    assert_eq!(d.coefficient(16), 0.0);
+    assert!(d.max_error() >= p.max_error());
+    for x in points(-2.0, 3.0, 201) {
+        assert_abs_near(d.eval(x), (x as f64).cos() as f32, d.max_error() + 1e-5);
+    }
+
+    let line = Chebyshev::<1>::fit(0.0, 4.0, |x| 3.0 * x - 1.0);
+    assert_abs_near(line.derivative().eval(1.7), 3.0, 1e-6);
+}
+
+#[test]
+fn test_integral() {
+    let a = -1.0_f64;
+    let p = Chebyshev::<16>::fit(a as f32, 2.0, |x| (x as f64).cos() as f32);
+    let i = p.integral();
+    assert_abs_near(i.eval(a as f32), 0.0, 1e-6);
+    for x in points(-1.0, 2.0, 201) {
+        let expected = (x as f64).sin() - a.sin();
+        assert_abs_near(i.eval(x), expected as f32, i.max_error() + 1e-5);
+    }
+
+    // Integrating and differentiating give back the series, but for the dropped term.
+    let back = i.derivative();
+    for x in points(-1.0, 2.0, 51) {
+        assert_abs_near(back.eval(x), p.eval(x), 1e-5);
+    }
+
+    // The integral of a constant has degree one, so all of it goes into the error.
+    let constant = Chebyshev::<0>::fit(2.0, 5.0, |_| 2.0);
+    assert_abs_near(constant.eval(4.0), 2.0, 1e-6);
+    assert_abs_near(constant.integral().eval(5.0), 0.0, 1e-6);
+    assert!(constant.integral().max_error() >= 6.0 - 1e-6);
+}
+
+#[test]
+#[should_panic]
+fn test_empty_interval() {
+    Chebyshev::<3>::fit(1.0, 1.0, |x| x);
+}
@@ -0,0 +1,103 @@
+#![cfg(test)]
+
+extern crate fastapprox;
+
+use fastapprox::codegen;
+
+#[path = "codegen/approximations.rs"]
+mod approximations;
+
+/// Functions rendered by the build script from the same approximations.
+mod generated {
+    include!(concat!(env!("OUT_DIR"), "/codegen.rs"));
+}
+
+fn inputs(lo: f32, hi: f32) -> Vec<f32> {
+    (0..=1000)
+        .map(|i| lo + (hi - lo) * i as f32 / 1000.0)
+        .collect()
+}
+
+fn horner(coefficients: &[f32], x: f32) -> f32 {
+    let (last, rest) = coefficients.split_last().unwrap();
+    rest.iter().rev().fold(*last, |acc, &c| c + x * acc)
+}
+
+#[test]
+fn test_source() {
+    let source = include_str!(concat!(env!("OUT_DIR"), "/codegen.rs"));
+    assert_eq!(source, approximations::source());
+}
+
+#[test]
+fn test_chebyshev() {
+    let curve = approximations::curve();
+    for x in inputs(-0.5, 2.5) {
+        assert_eq!(
+            generated::curve(x).to_bits(),
+            curve.eval(x).to_bits(),
+            "{}",
+            x
+        );
+    }
+}
+
+#[test]
+fn test_lut() {
+    let table = approximations::table();
+    for x in inputs(-5.0, 5.0) {
+        assert_eq!(
+            generated::table(x).to_bits(),
+            table.eval(x).to_bits(),
+            "{}",
+            x
+        );
+    }
+    assert!(generated::table(f32::NAN).is_nan());
+}
+
+#[test]
+fn test_polynomial() {
+    for x in inputs(-2.0, 2.0) {
+        let expected = horner(approximations::POLYNOMIAL, x);
+        assert_eq!(generated::polynomial(x).to_bits(), expected.to_bits());
+        let expected =
+            horner(approximations::NUMERATOR, x) / horner(approximations::DENOMINATOR, x);
+        assert_eq!(generated::rational(x).to_bits(), expected.to_bits());
+    }
+}
+
+#[test]
+fn test_literal() {
+    assert_eq!(codegen::literal(0.1), "0.1_f32");
+    assert_eq!(codegen::literal(-2.0), "-2.0_f32");
+    assert_eq!(codegen::literal(1e-40), "1e-40_f32");
+    assert_eq!(codegen::literal(f32::NAN), "f32::NAN");
+    assert_eq!(codegen::literal(f32::NEG_INFINITY), "f32::NEG_INFINITY");
+    for bits in (0..0x7f800000_u32).step_by(99_991) {
+        for x in [f32::from_bits(bits), -f32::from_bits(bits)] {
+            let literal = codegen::literal(x);
+            let parsed: f32 = literal.trim_end_matches("_f32").parse().unwrap();
+            assert_eq!(parsed.to_bits(), x.to_bits(), "{}", literal);
+        }
+    }
+}
+
+#[test]
+fn test_render() {
+    assert_eq!(
+        codegen::polynomial("line", "A line.\n\nIn two lines.", &[1.0, 0.5]),
+        "/// A line.\n///\n/// In two lines.\n#[inline]\npub fn line(x: f32) -> f32 {\n    \
+         1.0_f32 + x * (0.5_f32)\n}\n"
+    );
+    assert_eq!(
+        codegen::rational("one", "", &[1.0], &[1.0]),
+        "#[inline]\npub fn one(x: f32) -> f32 {\n    (1.0_f32) / (1.0_f32)\n}\n"
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_invalid_name() {
+    codegen::polynomial("not a name", "", &[1.0]);
+}
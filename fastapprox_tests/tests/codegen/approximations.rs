@@ -0,0 +1,40 @@
+//! Approximations rendered by the build script into `$OUT_DIR/codegen.rs`, and rebuilt by the
+//! codegen tests to check that the generated functions return the same values.
+
+use fastapprox::chebyshev::Chebyshev;
+use fastapprox::codegen;
+use fastapprox::lut::{Edge, Interpolation, Lut};
+
+pub fn curve() -> Chebyshev<10> {
+    Chebyshev::fit(0.0, 2.0, |x| (x as f64).sqrt().sin() as f32)
+}
+
+pub fn table() -> Lut<33> {
+    Lut::new(-4.0, 4.0, f32::tanh)
+        .with_interpolation(Interpolation::Cubic)
+        .with_edge(Edge::Extrapolate)
+}
+
+pub const POLYNOMIAL: &[f32] = &[1.0, -0.5, 2.5e-4, 3.0e7, -1.0 / 3.0];
+
+pub const NUMERATOR: &[f32] = &[0.1, 2.0];
+
+pub const DENOMINATOR: &[f32] = &[1.0, -0.75, 1e-20];
+
+pub fn source() -> String {
+    [
+        codegen::chebyshev(
+            "curve",
+            "Chebyshev series of `sin(sqrt(x))` on [0, 2].",
+            &curve(),
+        ),
+        codegen::lut(
+            "table",
+            "Table of `tanh` on [-4, 4].\n\nExtrapolated.",
+            &table(),
+        ),
+        codegen::polynomial("polynomial", "A polynomial.", POLYNOMIAL),
+        codegen::rational("rational", "A rational function.", NUMERATOR, DENOMINATOR),
+    ]
+    .join("\n")
+}
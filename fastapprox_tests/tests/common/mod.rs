@@ -0,0 +1,54 @@
+#![allow(dead_code)]
+
+/// Asserts that `actual` is close to `expected`.
+///
+/// Uses absolute difference for small expected values and relative difference otherwise,
+/// just like `compare` in `compare.rs`.
+pub fn assert_near(actual: f32, expected: f32, tolerance: f32) {
+    let d = if expected.abs() < 0.1 {
+        (actual - expected).abs()
+    } else {
+        ((actual - expected) / expected).abs()
+    };
+    assert!(
+        d < tolerance,
+        "actual = {}, but expected = {}, Δ == {}",
+        actual,
+        expected,
+        d
+    );
+}
+
+/// Asserts that every element of `actual` is close to the corresponding one in `expected`.
+pub fn assert_all_near(actual: &[f32], expected: &[f32], tolerance: f32) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) {
+        assert_near(*a, *e, tolerance);
+    }
+}
+
+/// Asserts that `actual` is within `tolerance` of `expected` in absolute terms.
+///
+/// This is the natural measure for logarithms, where it bounds the relative error of the
+/// underlying value.
+pub fn assert_abs_near(actual: f32, expected: f32, tolerance: f32) {
+    let d = (actual - expected).abs();
+    assert!(
+        d < tolerance,
+        "actual = {}, but expected = {}, Δ == {}",
+        actual,
+        expected,
+        d
+    );
+}
+
+/// Asserts that every element of `actual` is within `tolerance` of the one in `expected`.
+pub fn assert_all_abs_near(actual: &[f32], expected: &[f32], tolerance: f32) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) {
+        assert_abs_near(*a, *e, tolerance);
+    }
+}
+
+#[allow(unused_imports)]
+pub use fastapprox::reference::{bessel_i, exp_int_e1, exp_int_ei, hurwitz_zeta};
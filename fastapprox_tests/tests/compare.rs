@@ -9,16 +9,23 @@ use statrs::function::erf;
 use std::convert::Into;
 
 mod c;
+mod common;
+
+use common::{bessel_i, exp_int_e1, exp_int_ei, hurwitz_zeta};
 
 const FLOATS: &[f32] = &[-5.0, -0.25, -0.05, 0.0, 0.05, 1.0, 2.0, 3.0, 10.0];
 const POS_FLOATS: &[f32] = &[0.01, 0.05, 1.0, 2.1, 3.5, 100.0];
+const GREATER_THAN_ONES: &[f32] = &[1.01, 1.1, 1.5, 2.0, 3.0, 5.0, 10.0, 30.0];
 const BETWEEN_ONES: &[f32] = &[-0.9, -0.5, -0.1, -0.01, 0.0, 0.01, 0.1, 0.5, 0.9];
+// Just inside ±π, rather than an approximation of π.
+#[allow(clippy::approx_constant)]
 const BETWEEN_PIS: &[f32] = &[
     -3.14, -1.5, -1.0, -0.5, -0.1, -0.01, 0.0, 0.01, 0.1, 0.5, 1.0, 1.5, 3.14,
 ];
 const BETWEEN_HALFPIS: &[f32] = &[
     -1.56, -1.5, -1.0, -0.5, -0.1, -0.01, 0.0, 0.01, 0.1, 0.5, 1.0, 1.5, 1.56,
 ];
+const BEYOND_PERIODS: &[f32] = &[-100.0, -20.0, -10.0, -7.0, 7.0, 10.0, 20.0, 100.0];
 
 fn compare<F1, F2, T>(func: F1, base: F2, values: &[f32], tolerance: T)
 where
@@ -139,6 +146,12 @@ fn test_sigmoid_exact() {
     compare_far(faster::sigmoid, |x| (1.0_f32 + (-x).exp()).recip(), FLOATS);
 }
 
+#[test]
+fn test_rsqrt_exact() {
+    compare_near(fast::rsqrt, |x| x.sqrt().recip(), POS_FLOATS);
+    compare_far(faster::rsqrt, |x| x.sqrt().recip(), POS_FLOATS);
+}
+
 #[test]
 fn test_lgamma_approx() {
     compare_exact(fast::ln_gamma, c::fastlgamma, POS_FLOATS);
@@ -219,6 +232,20 @@ fn test_erfc_exact() {
     );
 }
 
+#[test]
+fn test_erfcx_exact() {
+    let erfcx = |x: f32| (special::Error::compl_error(x as f64) * (x as f64).powi(2).exp()) as f32;
+    compare_near(fast::erfcx, erfcx, FLOATS);
+    compare_far(faster::erfcx, erfcx, FLOATS);
+}
+
+#[test]
+fn test_ln_erfc_exact() {
+    let ln_erfc = |x: f32| special::Error::compl_error(x as f64).ln() as f32;
+    compare_near(fast::ln_erfc, ln_erfc, FLOATS);
+    compare_far(faster::ln_erfc, ln_erfc, FLOATS);
+}
+
 #[test]
 fn test_inverse_erf_approx() {
     compare_exact(fast::erf_inv, c::fastinverseerf, BETWEEN_ONES);
@@ -358,3 +385,190 @@ fn test_tanfull_exact() {
     compare_near(fast::tanfull, f32::tan, FLOATS);
     compare_far(faster::tanfull, f32::tan, FLOATS);
 }
+
+#[test]
+fn test_full_beyond_one_period() {
+    // The period count is negative for negative arguments beyond one period.
+    compare_exact(fast::sinfull, c::fastsinfull, BEYOND_PERIODS);
+    compare_exact(fast::cosfull, c::fastcosfull, BEYOND_PERIODS);
+    compare_exact(fast::tanfull, c::fasttanfull, BEYOND_PERIODS);
+    compare_near(fast::sinfull, f32::sin, BEYOND_PERIODS);
+    compare_near(fast::cosfull, f32::cos, BEYOND_PERIODS);
+    compare_near(fast::tanfull, f32::tan, BEYOND_PERIODS);
+    compare_far(faster::sinfull, f32::sin, BEYOND_PERIODS);
+    compare_far(faster::cosfull, f32::cos, BEYOND_PERIODS);
+    compare_far(faster::tanfull, f32::tan, BEYOND_PERIODS);
+}
+
+#[test]
+fn test_bessel_i0_exact() {
+    compare_near(fast::bessel_i0, |x| bessel_i(0, x as f64) as f32, FLOATS);
+    compare_far(faster::bessel_i0, |x| bessel_i(0, x as f64) as f32, FLOATS);
+}
+
+#[test]
+fn test_bessel_i1_exact() {
+    compare_near(fast::bessel_i1, |x| bessel_i(1, x as f64) as f32, FLOATS);
+    compare_far(faster::bessel_i1, |x| bessel_i(1, x as f64) as f32, FLOATS);
+}
+
+#[test]
+fn test_ln_bessel_i0_exact() {
+    compare_near(
+        fast::ln_bessel_i0,
+        |x| bessel_i(0, x as f64).ln() as f32,
+        FLOATS,
+    );
+    compare_far(
+        faster::ln_bessel_i0,
+        |x| bessel_i(0, x as f64).ln() as f32,
+        FLOATS,
+    );
+}
+
+#[test]
+fn test_bessel_i1_over_i0_exact() {
+    compare_near(
+        fast::bessel_i1_over_i0,
+        |x| (bessel_i(1, x as f64) / bessel_i(0, x as f64)) as f32,
+        FLOATS,
+    );
+    compare_far(
+        faster::bessel_i1_over_i0,
+        |x| (bessel_i(1, x as f64) / bessel_i(0, x as f64)) as f32,
+        FLOATS,
+    );
+}
+
+#[test]
+fn test_zeta_exact() {
+    compare_near(
+        fast::zeta,
+        |s| hurwitz_zeta(s as f64, 1.0) as f32,
+        GREATER_THAN_ONES,
+    );
+    compare_far(
+        faster::zeta,
+        |s| hurwitz_zeta(s as f64, 1.0) as f32,
+        GREATER_THAN_ONES,
+    );
+}
+
+#[test]
+fn test_hurwitz_zeta_exact() {
+    for &q in &[0.5_f32, 1.0, 2.1, 3.5, 100.0] {
+        compare_near(
+            |s| fast::hurwitz_zeta(s, q),
+            |s| hurwitz_zeta(s as f64, q as f64) as f32,
+            GREATER_THAN_ONES,
+        );
+        compare_far(
+            |s| faster::hurwitz_zeta(s, q),
+            |s| hurwitz_zeta(s as f64, q as f64) as f32,
+            &GREATER_THAN_ONES[..5],
+        );
+    }
+}
+
+#[test]
+fn test_exp_int_e1_exact() {
+    compare_near(
+        fast::exp_int_e1,
+        |x| exp_int_e1(x as f64) as f32,
+        POS_FLOATS,
+    );
+    compare_far(
+        faster::exp_int_e1,
+        |x| exp_int_e1(x as f64) as f32,
+        POS_FLOATS,
+    );
+}
+
+#[test]
+fn test_exp_int_ei_exact() {
+    const VALUES: &[f32] = &[
+        -20.0, -5.0, -1.0, -0.01, 0.01, 0.1, 1.0, 2.0, 5.9, 6.1, 10.0, 50.0,
+    ];
+
+    compare_near(fast::exp_int_ei, |x| exp_int_ei(x as f64) as f32, VALUES);
+    compare_far(faster::exp_int_ei, |x| exp_int_ei(x as f64) as f32, VALUES);
+}
+
+#[test]
+fn test_exp_int_ei_subnormal() {
+    for &x in &[1e-45_f32, 1e-42, 1e-39, 1.1754942e-38, 1.1754944e-38] {
+        for x in [x, -x] {
+            let exact = exp_int_ei(x as f64);
+            let fast_rel = ((fast::exp_int_ei(x) as f64 - exact) / exact).abs();
+            let faster_rel = ((faster::exp_int_ei(x) as f64 - exact) / exact).abs();
+            assert!(fast_rel <= 4e-4, "x: {}, rel: {}", x, fast_rel);
+            assert!(faster_rel <= 0.15, "x: {}, rel: {}", x, faster_rel);
+        }
+    }
+}
+
+#[test]
+fn test_exp_int_ei_root() {
+    // Ei changes sign at x0, so the error must be relative there rather than absolute.
+    const ROOT: f64 = 0.3725074107813666;
+    let near = (1..=8).flat_map(|k| {
+        let x = ROOT as f32;
+        [
+            f32::from_bits(x.to_bits() - k),
+            x,
+            f32::from_bits(x.to_bits() + k),
+        ]
+    });
+    let far = [1e-6, 1e-4, 1e-3, 1e-2, 0.1]
+        .iter()
+        .flat_map(|d| [(ROOT - d) as f32, (ROOT + d) as f32]);
+    for x in near.chain(far) {
+        let exact = exp_int_ei(x as f64);
+        for (approx, tol) in [(fast::exp_int_ei(x), 2e-6), (faster::exp_int_ei(x), 5e-4)] {
+            assert_eq!(approx < 0.0, exact < 0.0, "x: {}", x);
+            let rel = ((approx as f64 - exact) / exact).abs();
+            assert!(rel <= tol, "x: {}, rel: {}", x, rel);
+        }
+    }
+}
+
+#[test]
+fn test_log_int_known_values() {
+    // li(x) from two upwards, with the relative error documented for the faster tier, which
+    // shrinks as x grows.
+    const VALUES: &[(f32, f64, f64)] = &[
+        (2.0, 1.045163780117493, 0.1),
+        (2.25, 1.3777630337146836, 0.035),
+        (3.0, 2.163588594667192, 0.035),
+        (10.0, 6.165599504787298, 0.035),
+        (100.0, 30.12614158407963, 0.035),
+        (1000.0, 177.60965799015223, 0.035),
+        (1.0e4, 1246.1372158993885, 0.004),
+        (1.0e6, 78627.54915946218, 0.004),
+        (1.0e10, 455055614.5866231, 0.004),
+        (1.0e20, 2.2208196027836635e18, 0.004),
+    ];
+
+    for &(x, li, faster_tol) in VALUES {
+        let fast_rel = ((fast::log_int(x) as f64 - li) / li).abs();
+        let faster_rel = ((faster::log_int(x) as f64 - li) / li).abs();
+        assert!(fast_rel < 3e-4, "x: {}, rel: {}", x, fast_rel);
+        assert!(faster_rel < faster_tol, "x: {}, rel: {}", x, faster_rel);
+    }
+}
+
+#[test]
+fn test_log_int_exact() {
+    const VALUES: &[f32] = &[0.01, 0.5, 0.9, 1.1, 2.0, 10.0, 1000.0, 1.0e6];
+
+    compare_near(
+        fast::log_int,
+        |x| exp_int_ei((x as f64).ln()) as f32,
+        VALUES,
+    );
+    compare_far(
+        faster::log_int,
+        |x| exp_int_ei((x as f64).ln()) as f32,
+        VALUES,
+    );
+}
@@ -0,0 +1,317 @@
+#![cfg(test)]
+
+extern crate fastapprox;
+extern crate statrs;
+
+use fastapprox::dist::{fast, faster};
+use fastapprox::registry::{self, Tier};
+use statrs::distribution::{
+    Beta, Continuous, Discrete, Gamma, NegativeBinomial, Poisson, StudentsT,
+};
+use statrs::function::gamma::ln_gamma;
+
+mod common;
+
+use common::{assert_abs_near, bessel_i, hurwitz_zeta};
+
+const UNIT_FLOATS: &[f32] = &[0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99];
+const POS_FLOATS: &[f32] = &[0.01, 0.1, 0.5, 1.0, 2.1, 3.5, 10.0];
+const FLOATS: &[f32] = &[-10.0, -2.0, -0.5, 0.0, 0.1, 1.0, 3.0, 25.0];
+const ANGLES: &[f32] = &[-3.1, -2.0, -1.0, -0.1, 0.0, 0.1, 1.0, 2.0, 3.1];
+const COUNTS: &[u32] = &[0, 1, 2, 5, 10, 40];
+const POS_COUNTS: &[u32] = &[1, 2, 3, 10, 100, 5000];
+
+/// Absolute error bound of log-densities in the fast tier.
+const FAST_TOL: f32 = 0.01;
+
+fn assert_fast(actual: f32, expected: f32) {
+    assert_abs_near(actual, expected, FAST_TOL);
+}
+
+/// Error budget of a faster log-density, from the registry bound of each approximation in
+/// its formula.
+///
+/// Each term is the coefficient of a call in the formula, the name of the function, and the
+/// exact value of the call.
+fn faster_budget(terms: &[(f64, &str, f64)]) -> f32 {
+    let mut budget = 0.0;
+    let mut magnitude = 0.0;
+    for &(coef, name, exact) in terms {
+        let bound = registry::get(Tier::Faster, name).unwrap().error;
+        budget += coef.abs() * bound.tolerance(exact);
+        magnitude += (coef * exact).abs();
+    }
+    // Rounding of the sum in single precision.
+    (budget + 8.0 * f32::EPSILON as f64 * magnitude) as f32
+}
+
+#[test]
+fn test_gamma_ln_pdf() {
+    for &(shape, rate) in &[(0.5, 1.0), (2.0, 0.5), (7.5, 3.0)] {
+        let d = Gamma::new(shape as f64, rate as f64).unwrap();
+        let expected: Vec<f32> = POS_FLOATS
+            .iter()
+            .map(|&x| d.ln_pdf(x as f64) as f32)
+            .collect();
+        let budget: Vec<f32> = POS_FLOATS
+            .iter()
+            .map(|&x| {
+                let (shape, rate, x) = (shape as f64, rate as f64, x as f64);
+                faster_budget(&[
+                    (shape, "ln", rate.ln()),
+                    (1.0, "ln_gamma", ln_gamma(shape)),
+                    (shape - 1.0, "ln", x.ln()),
+                ])
+            })
+            .collect();
+        let mut out = vec![0.0; POS_FLOATS.len()];
+        for ((&x, &e), &tol) in POS_FLOATS.iter().zip(&expected).zip(&budget) {
+            assert_fast(fast::gamma_ln_pdf(x, shape, rate), e);
+            assert_abs_near(faster::gamma_ln_pdf(x, shape, rate), e, tol);
+        }
+        fast::gamma_ln_pdf_slice(POS_FLOATS, shape, rate, &mut out);
+        for (&a, &e) in out.iter().zip(&expected) {
+            assert_fast(a, e);
+        }
+        faster::gamma_ln_pdf_slice(POS_FLOATS, shape, rate, &mut out);
+        for ((&a, &e), &tol) in out.iter().zip(&expected).zip(&budget) {
+            assert_abs_near(a, e, tol);
+        }
+    }
+}
+
+#[test]
+fn test_beta_ln_pdf() {
+    for &(a, b) in &[(0.5, 0.5), (2.0, 5.0), (10.0, 3.0)] {
+        let d = Beta::new(a as f64, b as f64).unwrap();
+        let expected: Vec<f32> = UNIT_FLOATS
+            .iter()
+            .map(|&x| d.ln_pdf(x as f64) as f32)
+            .collect();
+        let budget: Vec<f32> = UNIT_FLOATS
+            .iter()
+            .map(|&x| {
+                let (a, b, x) = (a as f64, b as f64, x as f64);
+                faster_budget(&[
+                    (a - 1.0, "ln", x.ln()),
+                    (b - 1.0, "ln", (1.0 - x).ln()),
+                    (1.0, "ln_gamma", ln_gamma(a)),
+                    (1.0, "ln_gamma", ln_gamma(b)),
+                    (1.0, "ln_gamma", ln_gamma(a + b)),
+                ])
+            })
+            .collect();
+        let mut out = vec![0.0; UNIT_FLOATS.len()];
+        for ((&x, &e), &tol) in UNIT_FLOATS.iter().zip(&expected).zip(&budget) {
+            assert_fast(fast::beta_ln_pdf(x, a, b), e);
+            assert_abs_near(faster::beta_ln_pdf(x, a, b), e, tol);
+        }
+        fast::beta_ln_pdf_slice(UNIT_FLOATS, a, b, &mut out);
+        for (&a, &e) in out.iter().zip(&expected) {
+            assert_fast(a, e);
+        }
+        faster::beta_ln_pdf_slice(UNIT_FLOATS, a, b, &mut out);
+        for ((&a, &e), &tol) in out.iter().zip(&expected).zip(&budget) {
+            assert_abs_near(a, e, tol);
+        }
+    }
+}
+
+#[test]
+fn test_dirichlet_ln_pdf() {
+    let alpha = [0.5_f32, 1.0, 2.5, 7.0];
+    let x = [0.1_f32, 0.2, 0.3, 0.4];
+    let sum = alpha.iter().map(|&a| a as f64).sum();
+    let expected = ln_gamma(sum)
+        + alpha
+            .iter()
+            .zip(&x)
+            .map(|(&a, &x)| (a as f64 - 1.0) * (x as f64).ln() - ln_gamma(a as f64))
+            .sum::<f64>();
+    let mut terms = vec![(1.0, "ln_gamma", ln_gamma(sum))];
+    for (&a, &x) in alpha.iter().zip(&x) {
+        let (a, x) = (a as f64, x as f64);
+        terms.push((a - 1.0, "ln", x.ln()));
+        terms.push((1.0, "ln_gamma", ln_gamma(a)));
+    }
+    assert_fast(fast::dirichlet_ln_pdf(&x, &alpha), expected as f32);
+    assert_abs_near(
+        faster::dirichlet_ln_pdf(&x, &alpha),
+        expected as f32,
+        faster_budget(&terms),
+    );
+}
+
+#[test]
+fn test_poisson_ln_pmf() {
+    for &lambda in &[0.5_f32, 3.0, 20.0] {
+        let d = Poisson::new(lambda as f64).unwrap();
+        let expected: Vec<f32> = COUNTS.iter().map(|&k| d.ln_pmf(k as u64) as f32).collect();
+        let budget: Vec<f32> = COUNTS
+            .iter()
+            .map(|&k| {
+                let (k, lambda) = (k as f64, lambda as f64);
+                faster_budget(&[(k, "ln", lambda.ln()), (1.0, "ln_gamma", ln_gamma(k + 1.0))])
+            })
+            .collect();
+        let mut out = vec![0.0; COUNTS.len()];
+        for ((&k, &e), &tol) in COUNTS.iter().zip(&expected).zip(&budget) {
+            assert_fast(fast::poisson_ln_pmf(k, lambda), e);
+            assert_abs_near(faster::poisson_ln_pmf(k, lambda), e, tol);
+        }
+        fast::poisson_ln_pmf_slice(COUNTS, lambda, &mut out);
+        for (&a, &e) in out.iter().zip(&expected) {
+            assert_fast(a, e);
+        }
+        faster::poisson_ln_pmf_slice(COUNTS, lambda, &mut out);
+        for ((&a, &e), &tol) in out.iter().zip(&expected).zip(&budget) {
+            assert_abs_near(a, e, tol);
+        }
+    }
+}
+
+#[test]
+fn test_neg_binomial_ln_pmf() {
+    for &(r, p) in &[(1.0_f32, 0.3_f32), (3.0, 0.2), (10.5, 0.7)] {
+        let d = NegativeBinomial::new(r as f64, p as f64).unwrap();
+        let expected: Vec<f32> = COUNTS.iter().map(|&k| d.ln_pmf(k as u64) as f32).collect();
+        let budget: Vec<f32> = COUNTS
+            .iter()
+            .map(|&k| {
+                let (k, r, p) = (k as f64, r as f64, p as f64);
+                faster_budget(&[
+                    (1.0, "ln_gamma", ln_gamma(k + r)),
+                    (1.0, "ln_gamma", ln_gamma(k + 1.0)),
+                    (1.0, "ln_gamma", ln_gamma(r)),
+                    (r, "ln", p.ln()),
+                    (k, "ln", (1.0 - p).ln()),
+                ])
+            })
+            .collect();
+        let mut out = vec![0.0; COUNTS.len()];
+        for ((&k, &e), &tol) in COUNTS.iter().zip(&expected).zip(&budget) {
+            assert_fast(fast::neg_binomial_ln_pmf(k, r, p), e);
+            assert_abs_near(faster::neg_binomial_ln_pmf(k, r, p), e, tol);
+        }
+        fast::neg_binomial_ln_pmf_slice(COUNTS, r, p, &mut out);
+        for (&a, &e) in out.iter().zip(&expected) {
+            assert_fast(a, e);
+        }
+        faster::neg_binomial_ln_pmf_slice(COUNTS, r, p, &mut out);
+        for ((&a, &e), &tol) in out.iter().zip(&expected).zip(&budget) {
+            assert_abs_near(a, e, tol);
+        }
+    }
+}
+
+#[test]
+fn test_student_t_ln_pdf() {
+    for &(location, scale, freedom) in &[
+        (0.0_f32, 1.0_f32, 1.0_f32),
+        (1.0, 2.0, 4.5),
+        (-3.0, 0.5, 30.0),
+    ] {
+        let d = StudentsT::new(location as f64, scale as f64, freedom as f64).unwrap();
+        let expected: Vec<f32> = FLOATS.iter().map(|&x| d.ln_pdf(x as f64) as f32).collect();
+        let budget: Vec<f32> = FLOATS
+            .iter()
+            .map(|&x| {
+                let (scale, freedom) = (scale as f64, freedom as f64);
+                let z = (x as f64 - location as f64) / scale;
+                let halfnp1 = 0.5 * (freedom + 1.0);
+                faster_budget(&[
+                    (1.0, "ln_gamma", ln_gamma(halfnp1)),
+                    (1.0, "ln_gamma", ln_gamma(0.5 * freedom)),
+                    (0.5, "ln", freedom.ln()),
+                    (1.0, "ln", scale.ln()),
+                    (halfnp1, "ln", (1.0 + z * z / freedom).ln()),
+                ])
+            })
+            .collect();
+        let mut out = vec![0.0; FLOATS.len()];
+        for ((&x, &e), &tol) in FLOATS.iter().zip(&expected).zip(&budget) {
+            assert_fast(fast::student_t_ln_pdf(x, location, scale, freedom), e);
+            assert_abs_near(
+                faster::student_t_ln_pdf(x, location, scale, freedom),
+                e,
+                tol,
+            );
+        }
+        fast::student_t_ln_pdf_slice(FLOATS, location, scale, freedom, &mut out);
+        for (&a, &e) in out.iter().zip(&expected) {
+            assert_fast(a, e);
+        }
+        faster::student_t_ln_pdf_slice(FLOATS, location, scale, freedom, &mut out);
+        for ((&a, &e), &tol) in out.iter().zip(&expected).zip(&budget) {
+            assert_abs_near(a, e, tol);
+        }
+    }
+}
+
+#[test]
+fn test_von_mises_ln_pdf() {
+    for &(mu, kappa) in &[(0.0_f32, 0.1_f32), (0.5, 1.0), (-1.0, 4.0), (2.0, 50.0)] {
+        let ln_i0 = bessel_i(0, kappa as f64).ln();
+        let norm = (2.0 * std::f64::consts::PI).ln() + ln_i0;
+        let expected: Vec<f32> = ANGLES
+            .iter()
+            .map(|&x| (kappa as f64 * (x as f64 - mu as f64).cos() - norm) as f32)
+            .collect();
+        let budget: Vec<f32> = ANGLES
+            .iter()
+            .map(|&x| {
+                faster_budget(&[
+                    (kappa as f64, "cosfull", (x as f64 - mu as f64).cos()),
+                    (1.0, "ln_bessel_i0", ln_i0),
+                ])
+            })
+            .collect();
+        let mut out = vec![0.0; ANGLES.len()];
+        for ((&x, &e), &tol) in ANGLES.iter().zip(&expected).zip(&budget) {
+            assert_fast(fast::von_mises_ln_pdf(x, mu, kappa), e);
+            assert_abs_near(faster::von_mises_ln_pdf(x, mu, kappa), e, tol);
+        }
+        fast::von_mises_ln_pdf_slice(ANGLES, mu, kappa, &mut out);
+        for (&a, &e) in out.iter().zip(&expected) {
+            assert_fast(a, e);
+        }
+        faster::von_mises_ln_pdf_slice(ANGLES, mu, kappa, &mut out);
+        for ((&a, &e), &tol) in out.iter().zip(&expected).zip(&budget) {
+            assert_abs_near(a, e, tol);
+        }
+    }
+}
+
+#[test]
+fn test_zipf_ln_pmf() {
+    for &s in &[1.1_f32, 1.5, 2.0, 4.0] {
+        let zeta = hurwitz_zeta(s as f64, 1.0);
+        let expected: Vec<f32> = POS_COUNTS
+            .iter()
+            .map(|&k| (-(s as f64) * (k as f64).ln() - zeta.ln()) as f32)
+            .collect();
+        // The logarithm turns a relative error of zeta into an absolute one.
+        let zeta_rel = registry::get(Tier::Faster, "zeta").unwrap().error.rel;
+        let ln_zeta_tol = -(1.0 - zeta_rel).ln() as f32;
+        let budget: Vec<f32> = POS_COUNTS
+            .iter()
+            .map(|&k| {
+                ln_zeta_tol
+                    + faster_budget(&[(s as f64, "ln", (k as f64).ln()), (1.0, "ln", zeta.ln())])
+            })
+            .collect();
+        let mut out = vec![0.0; POS_COUNTS.len()];
+        for ((&k, &e), &tol) in POS_COUNTS.iter().zip(&expected).zip(&budget) {
+            assert_fast(fast::zipf_ln_pmf(k, s), e);
+            assert_abs_near(faster::zipf_ln_pmf(k, s), e, tol);
+        }
+        fast::zipf_ln_pmf_slice(POS_COUNTS, s, &mut out);
+        for (&a, &e) in out.iter().zip(&expected) {
+            assert_fast(a, e);
+        }
+        faster::zipf_ln_pmf_slice(POS_COUNTS, s, &mut out);
+        for ((&a, &e), &tol) in out.iter().zip(&expected).zip(&budget) {
+            assert_abs_near(a, e, tol);
+        }
+    }
+}
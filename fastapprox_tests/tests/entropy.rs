@@ -0,0 +1,169 @@
+#![cfg(test)]
+
+extern crate fastapprox;
+
+use fastapprox::entropy::{fast, faster};
+
+mod common;
+
+use common::assert_abs_near;
+
+const DISTRIBUTIONS: &[&[f32]] = &[
+    &[1.0],
+    &[0.5, 0.5],
+    &[0.25, 0.25, 0.5, 0.0],
+    &[0.1, 0.2, 0.3, 0.4],
+    &[0.97, 0.01, 0.01, 0.01],
+    &[1.0e-6, 0.5, 0.499999],
+];
+
+const NEAR: f32 = 0.01;
+const FAR: f32 = 0.15;
+const EPS: f32 = 1.0e-7;
+
+fn xlny(x: f64, y: f64) -> f64 {
+    if x == 0.0 {
+        0.0
+    } else {
+        x * y.ln()
+    }
+}
+
+fn entropy(p: &[f32]) -> f64 {
+    -p.iter().map(|&pi| xlny(pi as f64, pi as f64)).sum::<f64>()
+}
+
+fn cross_entropy(p: &[f32], q: &[f32]) -> f64 {
+    -p.iter()
+        .zip(q)
+        .map(|(&pi, &qi)| xlny(pi as f64, qi as f64))
+        .sum::<f64>()
+}
+
+fn clamp(q: &[f32]) -> Vec<f32> {
+    q.iter().map(|&qi| qi.clamp(EPS, 1.0 - EPS)).collect()
+}
+
+fn pairs() -> impl Iterator<Item = (&'static [f32], &'static [f32])> {
+    DISTRIBUTIONS.iter().flat_map(|&p| {
+        DISTRIBUTIONS
+            .iter()
+            .filter(move |q| q.len() == p.len())
+            .map(move |&q| (p, q))
+    })
+}
+
+fn check(fast: f32, faster: f32, exact: f64) {
+    if exact.is_infinite() {
+        assert_eq!(fast, exact as f32);
+        assert_eq!(faster, exact as f32);
+    } else {
+        assert_abs_near(fast, exact as f32, NEAR);
+        assert_abs_near(faster, exact as f32, FAR);
+    }
+}
+
+#[test]
+fn test_entropy() {
+    for p in DISTRIBUTIONS {
+        check(fast::entropy(p), faster::entropy(p), entropy(p));
+    }
+    assert_eq!(fast::entropy(&[1.0, 0.0]), 0.0);
+    assert_eq!(faster::entropy(&[0.0, 1.0]), 0.0);
+    assert_eq!(faster::entropy(&[]), 0.0);
+}
+
+#[test]
+fn test_cross_entropy() {
+    for (p, q) in pairs() {
+        check(
+            fast::cross_entropy(p, q),
+            faster::cross_entropy(p, q),
+            cross_entropy(p, q),
+        );
+        let e = cross_entropy(p, &clamp(q));
+        check(
+            fast::cross_entropy_clamped(p, q, EPS),
+            faster::cross_entropy_clamped(p, q, EPS),
+            e,
+        );
+        assert!(e.is_finite());
+    }
+}
+
+#[test]
+fn test_kl_divergence() {
+    for (p, q) in pairs() {
+        let e = cross_entropy(p, q) - entropy(p);
+        check(fast::kl_divergence(p, q), faster::kl_divergence(p, q), e);
+        let e = cross_entropy(p, &clamp(q)) - entropy(p);
+        check(
+            fast::kl_divergence_clamped(p, q, EPS),
+            faster::kl_divergence_clamped(p, q, EPS),
+            e,
+        );
+    }
+    for p in DISTRIBUTIONS {
+        assert_eq!(fast::kl_divergence(p, p), 0.0);
+    }
+}
+
+#[test]
+fn test_js_divergence() {
+    for (p, q) in pairs() {
+        let m: Vec<f32> = p.iter().zip(q).map(|(&a, &b)| 0.5 * (a + b)).collect();
+        let e = 0.5 * (cross_entropy(p, &m) - entropy(p) + cross_entropy(q, &m) - entropy(q));
+        assert!(e <= std::f64::consts::LN_2 + 1e-6);
+        check(fast::js_divergence(p, q), faster::js_divergence(p, q), e);
+        assert_eq!(fast::js_divergence(p, q), fast::js_divergence(q, p));
+        for js in [fast::js_divergence(p, q), faster::js_divergence(p, q)] {
+            assert!((0.0..=std::f32::consts::LN_2).contains(&js), "{}", js);
+        }
+    }
+    // Without the clamp, the error of faster::ln gives 0.6931534 here.
+    assert_eq!(
+        faster::js_divergence(&[0.07, 0.93, 0.0, 0.0], &[0.0, 0.0, 0.07, 0.93]),
+        std::f32::consts::LN_2
+    );
+    assert_abs_near(
+        fast::js_divergence(&[1.0, 0.0], &[0.0, 1.0]),
+        std::f32::consts::LN_2,
+        1e-4,
+    );
+}
+
+#[test]
+fn test_binary_cross_entropy() {
+    let p = [0.0_f32, 1.0e-6, 0.2, 0.5, 0.9, 1.0];
+    for &label in &[0.0_f32, 0.3, 1.0] {
+        let labels = [label; 6];
+        let bce = |p: &[f32]| {
+            p.iter()
+                .map(|&pi| {
+                    let y = label as f64;
+                    -xlny(y, pi as f64) - xlny(1.0 - y, 1.0 - pi as f64)
+                })
+                .sum::<f64>()
+                / p.len() as f64
+        };
+        check(
+            fast::binary_cross_entropy(&p, &labels),
+            faster::binary_cross_entropy(&p, &labels),
+            bce(&p),
+        );
+        // The terms reach ln(1 / EPS) ≈ 16, so the faster tolerance scales accordingly.
+        let e = bce(&clamp(&p)) as f32;
+        assert_abs_near(
+            fast::binary_cross_entropy_clamped(&p, &labels, EPS),
+            e,
+            NEAR,
+        );
+        assert_abs_near(
+            faster::binary_cross_entropy_clamped(&p, &labels, EPS),
+            e,
+            FAR,
+        );
+    }
+    assert_eq!(fast::binary_cross_entropy(&[1.0, 0.0], &[1.0, 0.0]), 0.0);
+    assert_eq!(fast::binary_cross_entropy(&[], &[]), 0.0);
+}
@@ -0,0 +1,161 @@
+#![cfg(test)]
+
+extern crate fastapprox;
+extern crate fastapprox_tests;
+
+use fastapprox::{fast, faster};
+use fastapprox_tests::fit::{refine_f32, solve, Kernel, Metric, Model};
+use fastapprox_tests::forms::{Form, FORMS};
+
+fn form(name: &str) -> &'static Form {
+    FORMS.iter().find(|f| f.name == name).unwrap()
+}
+
+#[test]
+fn test_metric() {
+    assert_eq!(Metric::Absolute.error(2.5, 2.0), 0.5);
+    assert_eq!(Metric::Relative.error(-2.5, -2.0), -0.25);
+    assert_eq!(Metric::Ulp.error(1.0 + 3.0 * 2f64.powi(-23), 1.0), 3.0);
+    assert_eq!(Metric::Ulp.error(4.0 - 2f64.powi(-22), 4.0), -0.5);
+}
+
+#[test]
+fn test_solve() {
+    let a = vec![
+        vec![0.0, 2.0, 1.0],
+        vec![1.0, 1.0, 0.0],
+        vec![3.0, 0.0, 1.0],
+    ];
+    let x = solve(a, vec![7.0, 3.0, 6.0]).unwrap();
+    for (actual, expected) in x.iter().zip(&[1.0, 2.0, 3.0]) {
+        assert!((actual - expected).abs() < 1e-12, "{:?}", x);
+    }
+    assert_eq!(
+        solve(vec![vec![1.0, 2.0], vec![2.0, 4.0]], vec![1.0, 2.0]),
+        None
+    );
+}
+
+fn cubic(c: &[f64], x: f64) -> f64 {
+    c[0] + x * (c[1] + x * (c[2] + x * c[3]))
+}
+
+fn line(c: &[f64], x: f64) -> f64 {
+    c[0] + c[1] * x
+}
+
+#[test]
+fn test_least_squares() {
+    let model = Model {
+        eval: cubic,
+        target: |x| 1.0 - 2.0 * x + 0.5 * x * x * x,
+        interval: (-1.0, 2.0),
+        metric: Metric::Absolute,
+    };
+    let fit = model.least_squares(&[0.0; 4], 50);
+    assert!(fit.max_error < 1e-10, "{:?}", fit);
+    for (actual, expected) in fit.params.iter().zip(&[1.0, -2.0, 0.0, 0.5]) {
+        assert!((actual - expected).abs() < 1e-9, "{:?}", fit);
+    }
+}
+
+#[test]
+fn test_minimax() {
+    // The best line to exp on [0, 1] has slope e - 1 and equioscillates at 0, ln(e - 1) and 1.
+    let model = Model {
+        eval: line,
+        target: f64::exp,
+        interval: (0.0, 1.0),
+        metric: Metric::Absolute,
+    };
+    let slope = std::f64::consts::E - 1.0;
+    let tangent = slope.ln();
+    let error = 0.5 * (1.0 - slope + slope * tangent);
+    let least_squares = model.least_squares(&[1.0, 1.0], 200);
+    let fit = model.minimax(&[1.0, 1.0], 200, 100);
+    assert!(fit.max_error < least_squares.max_error, "{:?}", fit);
+    assert!((fit.max_error - error).abs() < 1e-3 * error, "{:?}", fit);
+    assert!((fit.params[1] - slope).abs() < 1e-4, "{:?}", fit);
+    assert!((fit.params[0] - (1.0 - error)).abs() < 1e-4, "{:?}", fit);
+}
+
+#[test]
+fn test_kernels() {
+    for (name, f) in [
+        ("fast::log2", fast::log2 as fn(f32) -> f32),
+        ("fast::pow2", fast::pow2),
+        ("fast::erfc", fast::erfc),
+        ("faster::erfcx", faster::erfcx),
+        ("fast::exp_int_ei", fast::exp_int_ei),
+        ("fast::exp_int_ei_root", fast::exp_int_ei),
+        ("fast::exp_int_ei_large", fast::exp_int_ei),
+        ("faster::exp_int_ei", faster::exp_int_ei),
+        ("faster::exp_int_ei_root", faster::exp_int_ei),
+        ("faster::exp_int_ei_large", faster::exp_int_ei),
+    ] {
+        let form = form(name);
+        let params: Vec<f32> = form.original.iter().map(|&p| p as f32).collect();
+        for x in form.inputs(4099) {
+            assert_eq!(
+                (form.kernel.eval)(&params, x).to_bits(),
+                f(x).to_bits(),
+                "{} at {}",
+                name,
+                x
+            );
+        }
+    }
+}
+
+#[test]
+fn test_forms() {
+    for name in [
+        "fast::log2",
+        "fast::pow2",
+        "fast::erfc",
+        "faster::erfcx",
+        "fast::exp_int_ei",
+        "fast::exp_int_ei_root",
+        "fast::exp_int_ei_large",
+        "faster::exp_int_ei",
+        "faster::exp_int_ei_root",
+        "faster::exp_int_ei_large",
+    ] {
+        let form = form(name);
+        let grid = form.model.grid(400);
+        let original = form.model.max_error(form.original, &grid);
+        let fit = form.model.minimax(form.original, 400, 20);
+        assert!(fit.max_error <= original.max_error, "{}: {:?}", name, fit);
+        for (fitted, expected) in fit.params.iter().zip(form.minimax.unwrap_or(form.original)) {
+            assert!(
+                (fitted - expected).abs() < 0.01 * expected.abs(),
+                "{}: {:?}",
+                name,
+                fit
+            );
+        }
+    }
+}
+
+#[test]
+fn test_refine_f32() {
+    let form = form("fast::log2");
+    let inputs = form.inputs(1 << 12);
+    let params: Vec<f32> = form.original.iter().map(|&p| p as f32).collect();
+    let (original, _) = form.kernel.max_error(&params, &inputs);
+    let (refined, error) = refine_f32(&form.kernel, &params, &inputs, 64);
+    assert!(error <= original);
+    assert_eq!(form.kernel.max_error(&refined, &inputs).0, error);
+
+    // A constant perturbed by a few ulps is moved back.
+    let kernel = Kernel {
+        eval: |c, x| c[0] * x,
+        reference: |x| 3.0 * x,
+        metric: Metric::Relative,
+    };
+    let inputs = [1.0, 1.5, 2.0];
+    let start = f32::from_bits(3.0_f32.to_bits() + 5);
+    let (refined, error) = refine_f32(&kernel, &[start], &inputs, 16);
+    assert_eq!(refined, vec![3.0]);
+    assert_eq!(error, 0.0);
+}
@@ -0,0 +1,197 @@
+#![cfg(test)]
+
+extern crate fastapprox;
+extern crate statrs;
+
+use fastapprox::lda::{fast, faster, EStep};
+use statrs::function::gamma::digamma;
+
+mod common;
+
+use common::assert_all_abs_near;
+
+const TOPICS: usize = 3;
+const VOCABULARY: usize = 12;
+
+fn dirichlet_expectation(alpha: &[f64]) -> Vec<f64> {
+    let total = digamma(alpha.iter().sum());
+    alpha.iter().map(|&a| digamma(a) - total).collect()
+}
+
+/// Reference E-step in f64, iterated to full convergence, returning the fitted γ.
+fn lda_estep(counts: &[f64], exp_elog_beta: &[f64], alpha: &[f64]) -> Vec<f64> {
+    let n = counts.len();
+    let mut gamma = vec![1.0; TOPICS];
+    let theta = |gamma: &[f64]| -> Vec<f64> {
+        dirichlet_expectation(gamma)
+            .into_iter()
+            .map(f64::exp)
+            .collect()
+    };
+    let ratios = |theta: &[f64]| -> Vec<f64> {
+        (0..n)
+            .map(|i| {
+                let norm: f64 = (0..TOPICS)
+                    .map(|k| theta[k] * exp_elog_beta[k * n + i])
+                    .sum();
+                counts[i] / norm
+            })
+            .collect()
+    };
+    let mut t = theta(&gamma);
+    let mut r = ratios(&t);
+    for _ in 0..1000 {
+        let updated: Vec<f64> = (0..TOPICS)
+            .map(|k| {
+                let s: f64 = (0..n).map(|i| exp_elog_beta[k * n + i] * r[i]).sum();
+                alpha[k] + t[k] * s
+            })
+            .collect();
+        let change: f64 = updated.iter().zip(&gamma).map(|(a, b)| (a - b).abs()).sum();
+        gamma = updated;
+        t = theta(&gamma);
+        r = ratios(&t);
+        if change < 1e-12 {
+            break;
+        }
+    }
+    gamma
+}
+
+/// Topics concentrated on disjoint groups of four words, with a little mass everywhere else.
+fn topics() -> Vec<Vec<f64>> {
+    (0..TOPICS)
+        .map(|k| {
+            (0..VOCABULARY)
+                .map(|w| if w / 4 == k { 50.0 + w as f64 } else { 0.5 })
+                .collect()
+        })
+        .collect()
+}
+
+/// Documents as pairs of distinct word ids and counts.
+fn documents() -> Vec<(Vec<usize>, Vec<f64>)> {
+    let mut seed = 12345_u32;
+    let mut next = move || {
+        seed = seed.wrapping_mul(1664525).wrapping_add(1013904223);
+        seed >> 8
+    };
+    (0..20)
+        .map(|_| {
+            let ids: Vec<usize> = (0..VOCABULARY).filter(|_| next() % 3 != 0).collect();
+            let main = (next() % TOPICS as u32) as usize;
+            let counts = ids
+                .iter()
+                .map(|&w| {
+                    let c = (next() % 3) as f64;
+                    if w / 4 == main {
+                        c + 5.0
+                    } else {
+                        c
+                    }
+                })
+                .collect();
+            (ids, counts)
+        })
+        .collect()
+}
+
+fn normalized(gamma: &[f64]) -> Vec<f32> {
+    let total: f64 = gamma.iter().sum();
+    gamma.iter().map(|&g| (g / total) as f32).collect()
+}
+
+fn argmax(x: &[f32]) -> usize {
+    (0..x.len())
+        .max_by(|&a, &b| x[a].partial_cmp(&x[b]).unwrap())
+        .unwrap()
+}
+
+#[test]
+fn test_dirichlet_expectation() {
+    let alphas: &[&[f32]] = &[
+        &[1.0],
+        &[0.1, 0.5, 2.0],
+        &[1.0, 1.0, 1.0, 1.0],
+        &[3.5, 100.0],
+    ];
+    for alpha in alphas {
+        let exact = dirichlet_expectation(&alpha.iter().map(|&a| a as f64).collect::<Vec<_>>());
+        let exact: Vec<f32> = exact.into_iter().map(|e| e as f32).collect();
+        let mut out = vec![0.0; alpha.len()];
+        fast::dirichlet_expectation(alpha, &mut out);
+        assert_all_abs_near(&out, &exact, 0.01);
+        faster::dirichlet_expectation(alpha, &mut out);
+        assert_all_abs_near(&out, &exact, 0.15);
+    }
+}
+
+#[test]
+fn test_lda_estep_converges_to_exact_topics() {
+    let lambda = topics();
+    let params = EStep::default();
+    let alpha = [1.0 / TOPICS as f64; TOPICS];
+    let alpha32 = alpha.map(|a| a as f32);
+    let elog_beta: Vec<Vec<f64>> = lambda.iter().map(|l| dirichlet_expectation(l)).collect();
+    for (ids, counts) in documents() {
+        let n = ids.len();
+        let exp_elog_beta: Vec<f64> = elog_beta
+            .iter()
+            .flat_map(|row| ids.iter().map(move |&w| row[w].exp()))
+            .collect();
+        let expected = normalized(&lda_estep(&counts, &exp_elog_beta, &alpha));
+
+        let counts32: Vec<f32> = counts.iter().map(|&c| c as f32).collect();
+        let beta32: Vec<f32> = exp_elog_beta.iter().map(|&b| b as f32).collect();
+        let mut theta = [0.0_f32; TOPICS];
+        let mut phi_norm = vec![0.0_f32; n];
+
+        let mut gamma = [1.0_f32; TOPICS];
+        let iters = fast::lda_estep(
+            &counts32,
+            &beta32,
+            &alpha32,
+            &mut gamma,
+            &mut theta,
+            &mut phi_norm,
+            &params,
+        );
+        assert!(iters < params.max_iter);
+        let actual = normalized(&gamma.map(|g| g as f64));
+        assert_all_abs_near(&actual, &expected, 0.01);
+        assert_eq!(argmax(&actual), argmax(&expected));
+
+        let mut gamma = [1.0_f32; TOPICS];
+        let iters = faster::lda_estep(
+            &counts32,
+            &beta32,
+            &alpha32,
+            &mut gamma,
+            &mut theta,
+            &mut phi_norm,
+            &params,
+        );
+        assert!(iters < params.max_iter);
+        let actual = normalized(&gamma.map(|g| g as f64));
+        assert_all_abs_near(&actual, &expected, 0.15);
+        assert_eq!(argmax(&actual), argmax(&expected));
+    }
+}
+
+#[test]
+fn test_lda_estep_empty_document() {
+    let alpha = [0.1_f32, 0.2];
+    let mut gamma = [1.0_f32; 2];
+    let mut theta = [0.0_f32; 2];
+    let iters = fast::lda_estep(
+        &[],
+        &[],
+        &alpha,
+        &mut gamma,
+        &mut theta,
+        &mut [],
+        &EStep::default(),
+    );
+    assert_eq!(iters, 0);
+    assert_eq!(gamma, alpha);
+}
@@ -0,0 +1,149 @@
+#![cfg(test)]
+
+extern crate fastapprox;
+
+use fastapprox::logspace::{exact, fast, faster};
+
+mod common;
+
+use common::{assert_abs_near, assert_near};
+
+const LNS: &[f32] = &[
+    f32::NEG_INFINITY,
+    -1.0e4,
+    -700.0,
+    -30.0,
+    -1.0,
+    -0.25,
+    0.0,
+    0.05,
+    3.0,
+    50.0,
+];
+
+const NEAR: f32 = 0.01;
+const FAR: f32 = 0.15;
+
+fn log_add_exp(a: f32, b: f32) -> f32 {
+    let (a, b) = (a as f64, b as f64);
+    let m = a.max(b);
+    if m == f64::NEG_INFINITY {
+        return f32::NEG_INFINITY;
+    }
+    (m + ((a - m).exp() + (b - m).exp()).ln()) as f32
+}
+
+fn assert_ln_near(actual: f32, expected: f32, tolerance: f32) {
+    if expected == f32::NEG_INFINITY {
+        assert_eq!(actual, expected);
+    } else {
+        assert_abs_near(actual, expected, tolerance);
+    }
+}
+
+#[test]
+fn test_add() {
+    for &a in LNS {
+        for &b in LNS {
+            let e = log_add_exp(a, b);
+            let r = exact::LogF32::from_ln(a) + exact::LogF32::from_ln(b);
+            assert_ln_near(r.ln(), e, 1e-6 * e.abs().max(1.0));
+            let r = fast::LogF32::from_ln(a) + fast::LogF32::from_ln(b);
+            assert_ln_near(r.ln(), e, NEAR);
+            let r = faster::LogF32::from_ln(a) + faster::LogF32::from_ln(b);
+            assert_ln_near(r.ln(), e, FAR);
+        }
+    }
+}
+
+#[test]
+fn test_mul_div() {
+    for &a in LNS {
+        for &b in &LNS[1..] {
+            let (x, y) = (fast::LogF32::from_ln(a), fast::LogF32::from_ln(b));
+            assert_eq!((x * y).ln(), a + b);
+            assert_eq!((x / y).ln(), a - b);
+        }
+    }
+}
+
+#[test]
+fn test_zero() {
+    let zero = fast::LogF32::ZERO;
+    assert_eq!(fast::LogF32::new(0.0), zero);
+    assert_eq!(fast::LogF32::default(), zero);
+    assert_eq!(zero.value(), 0.0);
+    assert!(zero.is_zero());
+    assert!(zero + zero == zero);
+    for &a in LNS {
+        let x = fast::LogF32::from_ln(a);
+        assert_eq!(x + zero, x);
+        assert_eq!(zero + x, x);
+        assert_eq!(x * zero, zero);
+        let x = faster::LogF32::from_ln(a);
+        assert_eq!(x + faster::LogF32::ZERO, x);
+    }
+    assert_eq!(
+        Vec::<fast::LogF32>::new().into_iter().sum::<fast::LogF32>(),
+        zero
+    );
+    assert_eq!(
+        Vec::<fast::LogF32>::new().iter().product::<fast::LogF32>(),
+        fast::LogF32::ONE
+    );
+}
+
+#[test]
+fn test_conversions() {
+    for &x in &[1.0e-30_f32, 0.001, 0.5, 1.0, 7.0, 1.0e20] {
+        assert_near(exact::LogF32::new(x).value(), x, 1e-5);
+        assert_near(f32::from(fast::LogF32::from(x)), x, NEAR);
+        assert_near(f32::from(faster::LogF32::from(x)), x, FAR);
+        assert_abs_near(fast::LogF32::new(x).ln(), x.ln(), NEAR);
+    }
+}
+
+#[test]
+fn test_sum_of_underflowing_terms() {
+    let lns: Vec<f32> = (0..100).map(|i| -1000.0 - i as f32 * 0.1).collect();
+    let m = lns[0] as f64;
+    let e = (m + lns.iter().map(|&l| (l as f64 - m).exp()).sum::<f64>().ln()) as f32;
+    let s: fast::LogF32 = lns.iter().map(|&l| fast::LogF32::from_ln(l)).sum();
+    assert_near(s.ln(), e, NEAR);
+    let s: faster::LogF32 = lns.iter().map(|&l| faster::LogF32::from_ln(l)).sum();
+    assert_near(s.ln(), e, FAR);
+}
+
+/// Log-likelihood of a long observation sequence under a two-state HMM, using the forward
+/// algorithm with the given log-space type.
+macro_rules! forward {
+    ($tier:ident) => {{
+        use $tier::LogF32;
+        let start = [LogF32::new(0.6), LogF32::new(0.4)];
+        let trans = [
+            [LogF32::new(0.7), LogF32::new(0.3)],
+            [LogF32::new(0.4), LogF32::new(0.6)],
+        ];
+        let emit = [
+            [LogF32::new(0.5), LogF32::new(0.4), LogF32::new(0.1)],
+            [LogF32::new(0.1), LogF32::new(0.3), LogF32::new(0.6)],
+        ];
+        let mut alpha = [start[0] * emit[0][0], start[1] * emit[1][0]];
+        for t in 1..1000 {
+            let o = (t * 7 + t / 3) % 3;
+            alpha = [
+                (alpha[0] * trans[0][0] + alpha[1] * trans[1][0]) * emit[0][o],
+                (alpha[0] * trans[0][1] + alpha[1] * trans[1][1]) * emit[1][o],
+            ];
+        }
+        (alpha[0] + alpha[1]).ln()
+    }};
+}
+
+#[test]
+fn test_forward_algorithm() {
+    let e = forward!(exact);
+    assert!(e < -700.0);
+    assert_near(forward!(fast), e, NEAR);
+    assert_near(forward!(faster), e, FAR);
+}
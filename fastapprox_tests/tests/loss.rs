@@ -0,0 +1,144 @@
+#![cfg(test)]
+
+extern crate fastapprox;
+
+use fastapprox::loss::{fast, faster};
+
+mod common;
+
+use common::{assert_abs_near, assert_near};
+
+const MARGINS: &[f32] = &[
+    -1.0e4, -100.0, -20.0, -5.0, -1.0, -0.25, 0.0, 0.05, 1.0, 3.0, 10.0, 100.0, 1.0e4,
+];
+const PROBS: &[f32] = &[0.0, 1.0e-9, 0.001, 0.1, 0.5, 0.75, 0.999, 1.0];
+const PREDICTIONS: &[f32] = &[-20.0, -3.0, -0.5, 0.0, 0.5, 2.0, 5.0, 20.0];
+
+const NEAR: f32 = 0.01;
+const FAR: f32 = 0.15;
+
+fn check<F1, F2, F3>(fast: F1, faster: F2, exact: F3, values: &[f32])
+where
+    F1: Fn(f32) -> (f32, f32),
+    F2: Fn(f32) -> (f32, f32),
+    F3: Fn(f64) -> (f64, f64),
+{
+    for &x in values {
+        let (l, g) = exact(x as f64);
+        let (fl, fg) = fast(x);
+        assert_near(fl, l as f32, NEAR);
+        assert_near(fg, g as f32, NEAR);
+        let (fl, fg) = faster(x);
+        assert_near(fl, l as f32, FAR);
+        assert_near(fg, g as f32, FAR);
+    }
+}
+
+#[test]
+fn test_logistic_loss() {
+    for &label in &[-1.0_f32, 1.0] {
+        let y = label as f64;
+        let exact = |p: f64| {
+            let m = -y * p;
+            let loss = m.max(0.0) + (-m.abs()).exp().ln_1p();
+            (loss, -y / (1.0 + (-m).exp()))
+        };
+        check(
+            |p| fast::logistic_loss(p, label),
+            |p| faster::logistic_loss(p, label),
+            exact,
+            MARGINS,
+        );
+    }
+}
+
+#[test]
+fn test_log_loss() {
+    let eps = fast::PROB_EPS;
+    for &label in &[0.0_f32, 1.0] {
+        let y = label as f64;
+        let exact = |p: f64| {
+            let p = (p as f32).clamp(eps, 1.0 - eps) as f64;
+            (
+                -y * p.ln() - (1.0 - y) * (1.0 - p).ln(),
+                (p - y) / (p * (1.0 - p)),
+            )
+        };
+        check(
+            |p| fast::log_loss(p, label),
+            |p| faster::log_loss(p, label),
+            exact,
+            PROBS,
+        );
+    }
+}
+
+#[test]
+fn test_poisson_loss() {
+    for &label in &[0.0_f32, 1.0, 7.0] {
+        for &p in PREDICTIONS {
+            // The loss and its derivative are differences, so errors scale with the terms.
+            let rate = (p as f64).exp();
+            let scale = (rate + (label * p).abs() as f64).max(1.0) as f32;
+            let (l, g) = (rate - (label * p) as f64, rate - label as f64);
+            let (fl, fg) = fast::poisson_loss(p, label);
+            assert_abs_near(fl, l as f32, NEAR * scale);
+            assert_abs_near(fg, g as f32, NEAR * scale);
+            let (fl, fg) = faster::poisson_loss(p, label);
+            assert_abs_near(fl, l as f32, FAR * scale);
+            assert_abs_near(fg, g as f32, FAR * scale);
+        }
+    }
+}
+
+#[test]
+fn test_quantile_loss() {
+    for &tau in &[0.1_f32, 0.5, 0.9] {
+        let t = tau as f64;
+        let exact = |p: f64| {
+            let r = 1.0 - p;
+            if r >= 0.0 {
+                (t * r, -t)
+            } else {
+                ((t - 1.0) * r, 1.0 - t)
+            }
+        };
+        check(
+            |p| fast::quantile_loss(p, 1.0, tau),
+            |p| faster::quantile_loss(p, 1.0, tau),
+            exact,
+            PREDICTIONS,
+        );
+    }
+}
+
+#[test]
+fn test_squared_loss() {
+    let exact = |p: f64| ((p - 0.5).powi(2), 2.0 * (p - 0.5));
+    check(
+        |p| fast::squared_loss(p, 0.5),
+        |p| faster::squared_loss(p, 0.5),
+        exact,
+        PREDICTIONS,
+    );
+}
+
+#[test]
+fn test_hinge_loss() {
+    for &label in &[-1.0_f32, 1.0] {
+        let y = label as f64;
+        let exact = |p: f64| {
+            if 1.0 - y * p > 0.0 {
+                (1.0 - y * p, -y)
+            } else {
+                (0.0, 0.0)
+            }
+        };
+        check(
+            |p| fast::hinge_loss(p, label),
+            |p| faster::hinge_loss(p, label),
+            exact,
+            MARGINS,
+        );
+    }
+}
@@ -0,0 +1,142 @@
+#![cfg(test)]
+
+extern crate fastapprox;
+
+use fastapprox::lut::{Edge, Interpolation, Lut};
+use fastapprox::{fast, faster};
+
+mod common;
+
+use common::assert_abs_near;
+
+const MODES: [Interpolation; 3] = [
+    Interpolation::Nearest,
+    Interpolation::Linear,
+    Interpolation::Cubic,
+];
+
+const CUBES: Lut<5> = Lut::from_table(-2.0, 2.0, [-8.0, -1.0, 0.0, 1.0, 8.0]);
+
+fn sigmoid(x: f32) -> f32 {
+    (1.0 / (1.0 + (-x as f64).exp())) as f32
+}
+
+fn tanh(x: f32) -> f32 {
+    (x as f64).tanh() as f32
+}
+
+/// Maximum absolute error of `f` against `g` over evenly spaced points of `[lo, hi]`.
+fn max_error(lo: f32, hi: f32, f: impl Fn(f32) -> f32, g: fn(f32) -> f32) -> f32 {
+    let n = 100_000;
+    (0..=n)
+        .map(|i| (lo as f64 + (hi - lo) as f64 * i as f64 / n as f64) as f32)
+        .map(|x| (f(x) - g(x)).abs())
+        .fold(0.0, f32::max)
+}
+
+#[test]
+fn test_nodes() {
+    assert_eq!(CUBES.domain(), (-2.0, 2.0));
+    assert_eq!(CUBES.table(), &[-8.0, -1.0, 0.0, 1.0, 8.0]);
+    for mode in MODES {
+        let lut = CUBES.with_interpolation(mode);
+        for (i, &y) in CUBES.table().iter().enumerate() {
+            assert_eq!(lut.eval(i as f32 - 2.0), y, "{:?}", mode);
+        }
+    }
+}
+
+#[test]
+fn test_new() {
+    let lut = Lut::<5>::new(-2.0, 2.0, |x| x * x * x);
+    assert_eq!(lut, CUBES);
+    let lut = Lut::<101>::new(0.0, 1.0, |x| x);
+    for (i, &y) in lut.table().iter().enumerate() {
+        assert_abs_near(y, i as f32 / 100.0, 1e-7);
+    }
+}
+
+#[test]
+fn test_nearest() {
+    let lut = CUBES.with_interpolation(Interpolation::Nearest);
+    assert_eq!(lut.eval(-1.6), -8.0);
+    assert_eq!(lut.eval(-1.4), -1.0);
+    assert_eq!(lut.eval(0.4), 0.0);
+    assert_eq!(lut.eval(0.6), 1.0);
+    assert_eq!(lut.eval(1.9), 8.0);
+}
+
+#[test]
+fn test_linear() {
+    assert_eq!(CUBES.eval(1.25), 2.75);
+    assert_eq!(CUBES.eval(-0.5), -0.5);
+    let line = Lut::<7>::new(-3.0, 3.0, |x| 2.0 * x + 1.0);
+    for i in 0..=60 {
+        let x = -3.0 + 0.1 * i as f32;
+        assert_abs_near(line.eval(x), 2.0 * x + 1.0, 1e-5);
+    }
+}
+
+#[test]
+fn test_cubic() {
+    // Catmull-Rom splines reproduce quadratics away from the ends of the table.
+    let lut = Lut::<9>::new(0.0, 8.0, |x| x * x - 3.0 * x).with_interpolation(Interpolation::Cubic);
+    for i in 10..=70 {
+        let x = 0.1 * i as f32;
+        assert_abs_near(lut.eval(x), x * x - 3.0 * x, 1e-5);
+    }
+    // The spline stays between its neighbours for monotone data here.
+    let lut = CUBES.with_interpolation(Interpolation::Cubic);
+    assert!(lut.eval(1.5) > 1.0 && lut.eval(1.5) < 8.0);
+    assert!(lut.eval(-1.5) > -8.0 && lut.eval(-1.5) < -1.0);
+}
+
+#[test]
+fn test_edges() {
+    for mode in MODES {
+        let clamped = CUBES.with_interpolation(mode);
+        assert_eq!(clamped.eval(-10.0), -8.0);
+        assert_eq!(clamped.eval(2.5), 8.0);
+        assert_eq!(clamped.eval(f32::INFINITY), 8.0);
+        assert!(clamped.eval(f32::NAN).is_nan());
+
+        let extrapolated = clamped.with_edge(Edge::Extrapolate);
+        assert_eq!(extrapolated.eval(-3.0), -15.0);
+        assert_eq!(extrapolated.eval(2.5), 11.5);
+        assert_eq!(extrapolated.eval(1.0), 1.0);
+        assert!(extrapolated.eval(f32::NAN).is_nan());
+    }
+}
+
+#[test]
+fn test_accuracy() {
+    let linear = Lut::<256>::new(-8.0, 8.0, sigmoid);
+    let cubic = linear.with_interpolation(Interpolation::Cubic);
+    let nearest = linear.with_interpolation(Interpolation::Nearest);
+    let e_nearest = max_error(-8.0, 8.0, |x| nearest.eval(x), sigmoid);
+    let e_linear = max_error(-8.0, 8.0, |x| linear.eval(x), sigmoid);
+    let e_cubic = max_error(-8.0, 8.0, |x| cubic.eval(x), sigmoid);
+    let e_fast = max_error(-8.0, 8.0, fast::sigmoid, sigmoid);
+    let e_faster = max_error(-8.0, 8.0, faster::sigmoid, sigmoid);
+    assert!(e_nearest < 2e-2, "{}", e_nearest);
+    assert!(e_linear < 1e-4, "{}", e_linear);
+    assert!(e_cubic < 5e-6, "{}", e_cubic);
+    assert!(e_cubic < e_fast && e_linear < e_faster);
+
+    let cubic = Lut::<512>::new(-5.0, 5.0, tanh).with_interpolation(Interpolation::Cubic);
+    let e_cubic = max_error(-5.0, 5.0, |x| cubic.eval(x), tanh);
+    assert!(e_cubic < 5e-6, "{}", e_cubic);
+    assert!(e_cubic < max_error(-5.0, 5.0, fast::tanh, tanh));
+}
+
+#[test]
+#[should_panic]
+fn test_single_entry() {
+    Lut::<1>::new(0.0, 1.0, |x| x);
+}
+
+#[test]
+#[should_panic]
+fn test_empty_interval() {
+    Lut::<4>::from_table(1.0, 1.0, [0.0; 4]);
+}
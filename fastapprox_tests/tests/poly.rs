@@ -0,0 +1,125 @@
+#![cfg(test)]
+
+extern crate fastapprox;
+
+use fastapprox::poly::{exp2_deg, log2_deg};
+
+/// Every 997th float of `[lo, hi]`, for positive `lo`.
+fn floats(lo: f32, hi: f32) -> impl Iterator<Item = f32> {
+    (lo.to_bits()..=hi.to_bits())
+        .step_by(997)
+        .map(f32::from_bits)
+}
+
+fn max_exp2_error(f: fn(f32) -> f32) -> f64 {
+    floats(1e-8, 127.0)
+        .flat_map(|p| [p, -p])
+        .filter(|&p| p >= -126.0)
+        .map(|p| {
+            let exact = (p as f64).exp2();
+            ((f(p) as f64 - exact) / exact).abs()
+        })
+        .fold(0.0, f64::max)
+}
+
+fn max_log2_error(f: fn(f32) -> f32) -> f64 {
+    floats(1e-30, 1e30)
+        .map(|x| (f(x) as f64 - (x as f64).log2()).abs())
+        .fold(0.0, f64::max)
+}
+
+fn max_log2_relative_error(f: fn(f32) -> f32) -> f64 {
+    floats(0.5, 2.0)
+        .filter(|&x| x != 1.0)
+        .map(|x| {
+            let exact = (x as f64).log2();
+            ((f(x) as f64 - exact) / exact).abs()
+        })
+        .fold(0.0, f64::max)
+}
+
+/// Asserts that the errors are within the bounds, and decrease with the degree.
+fn assert_bounds(name: &str, errors: [f64; 6], bounds: [f64; 6]) {
+    for (degree, (error, bound)) in errors.iter().zip(bounds).enumerate() {
+        assert!(
+            *error <= bound,
+            "{} degree {}: {:e}",
+            name,
+            degree + 1,
+            error
+        );
+    }
+    assert!(errors.windows(2).all(|w| w[1] < w[0]), "{:?}", errors);
+}
+
+#[test]
+fn test_exp2_error() {
+    assert_bounds(
+        "exp2",
+        [
+            max_exp2_error(exp2_deg::<1>),
+            max_exp2_error(exp2_deg::<2>),
+            max_exp2_error(exp2_deg::<3>),
+            max_exp2_error(exp2_deg::<4>),
+            max_exp2_error(exp2_deg::<5>),
+            max_exp2_error(exp2_deg::<6>),
+        ],
+        [3.0e-2, 1.8e-3, 7.5e-5, 2.7e-6, 1.8e-7, 1.1e-7],
+    );
+}
+
+#[test]
+fn test_log2_error() {
+    assert_bounds(
+        "log2",
+        [
+            max_log2_error(log2_deg::<1>),
+            max_log2_error(log2_deg::<2>),
+            max_log2_error(log2_deg::<3>),
+            max_log2_error(log2_deg::<4>),
+            max_log2_error(log2_deg::<5>),
+            max_log2_error(log2_deg::<6>),
+        ],
+        [8.6e-2, 5.7e-3, 8.6e-4, 1.1e-4, 1.9e-5, 6.0e-6],
+    );
+    assert_bounds(
+        "log2 relative",
+        [
+            max_log2_relative_error(log2_deg::<1>),
+            max_log2_relative_error(log2_deg::<2>),
+            max_log2_relative_error(log2_deg::<3>),
+            max_log2_relative_error(log2_deg::<4>),
+            max_log2_relative_error(log2_deg::<5>),
+            max_log2_relative_error(log2_deg::<6>),
+        ],
+        [1.8e-1, 2.9e-2, 3.4e-3, 7.3e-4, 1.1e-4, 1.8e-5],
+    );
+}
+
+#[test]
+fn test_exp2_edges() {
+    assert_eq!(exp2_deg::<6>(0.0), 1.0);
+    assert_eq!(exp2_deg::<3>(128.0), f32::INFINITY);
+    assert_eq!(exp2_deg::<3>(1000.0), f32::INFINITY);
+    assert_eq!(exp2_deg::<3>(-1000.0), exp2_deg::<3>(-126.0));
+    assert!(exp2_deg::<3>(-126.0) > 0.0);
+    assert!(exp2_deg::<3>(f32::NAN).is_nan());
+    // The reduction keeps the fraction in [0, 1) at negative integers too.
+    for p in [-126.0, -5.0, -1.0, 1.0, 7.0, 127.0] {
+        assert_eq!(exp2_deg::<2>(p), exp2_deg::<2>(0.0) * p.exp2());
+    }
+}
+
+#[test]
+fn test_log2_powers() {
+    // log2(2^e m) = e + log2(m), with the same `m` for every `e`.
+    for e in -125..=127 {
+        let scale = (e as f32).exp2();
+        for m in [0.75_f32, 1.0, 1.3] {
+            let expected = e as f32 + log2_deg::<4>(m);
+            assert_eq!(log2_deg::<4>(scale * m), expected, "2^{} {}", e, m);
+        }
+    }
+    assert_eq!(log2_deg::<1>(1.0), 0.0);
+    assert_eq!(log2_deg::<6>(1.0), 0.0);
+}
@@ -0,0 +1,155 @@
+#![cfg(test)]
+
+extern crate fastapprox;
+extern crate fastapprox_tests;
+
+use fastapprox::precise;
+use fastapprox::reference;
+use fastapprox::registry::{self, Tier, ALL};
+use fastapprox_tests::accuracy::{ulp_distance, Sweep};
+
+/// Distance between swept floats, odd so that it does not line up with the other sweeps.
+const STRIDE: u64 = 8191;
+
+/// Largest error of every precise function, in units in the last place.
+const MAX_ULP: u64 = 1;
+
+fn assert_ulp(name: &str, actual: f32, expected: f64) {
+    let expected = expected as f32;
+    assert!(
+        ulp_distance(actual, expected) <= MAX_ULP,
+        "{}: {} instead of {}",
+        name,
+        actual,
+        expected
+    );
+}
+
+#[test]
+fn test_ulp_bounds() {
+    let sweep = Sweep {
+        stride: STRIDE,
+        ..Sweep::default()
+    };
+    for f in ALL.iter().filter(|f| f.tier == Tier::Precise) {
+        let stats = sweep.run(f.approx, f.reference, f.domain.0, f.domain.1);
+        assert_eq!(stats.invalid, 0, "{}", f.qualified_name());
+        assert!(
+            stats.max_ulp.0 <= MAX_ULP,
+            "{}: {} ulp at {}",
+            f.qualified_name(),
+            stats.max_ulp.0,
+            stats.max_ulp.1
+        );
+    }
+}
+
+#[test]
+fn test_trigonometry_full_range() {
+    let sweep = Sweep {
+        stride: STRIDE * 16,
+        ..Sweep::default()
+    };
+    for name in ["sinfull", "cosfull", "tanfull"] {
+        let f = registry::get(Tier::Precise, name).unwrap();
+        let stats = sweep.run(f.approx, f.reference, -f32::MAX, f32::MAX);
+        assert_eq!(stats.invalid, 0, "{}", name);
+        assert!(stats.max_ulp.0 <= MAX_ULP, "{}: {:?}", name, stats.max_ulp);
+    }
+    // The reduction of large arguments needs bits of 2/π far after the point.
+    let x = 1e22_f32;
+    assert_ulp("sinfull", precise::sinfull(x), (x as f64).sin());
+    assert_ulp("cosfull", precise::cosfull(x), (x as f64).cos());
+}
+
+#[test]
+fn test_two_arguments() {
+    for i in 0..60 {
+        let x = 1e-3_f32 * 1.2_f32.powi(i);
+        for j in -40..=40 {
+            let p = 0.77 * j as f32;
+            let expected = (x as f64).powf(p as f64);
+            if (expected as f32).is_normal() {
+                assert_ulp("pow", precise::pow(x, p), expected);
+            }
+        }
+    }
+    for i in 0..40 {
+        let s = 1.001_f32 + 0.4 * i as f32;
+        for j in 0..40 {
+            let q = 1e-2_f32 * 1.3_f32.powi(j);
+            let expected = reference::hurwitz_zeta(s as f64, q as f64);
+            if (expected as f32).is_finite() {
+                assert_ulp("hurwitz_zeta", precise::hurwitz_zeta(s, q), expected);
+            }
+        }
+    }
+    assert_eq!(precise::pow(0.0, 0.0), 1.0);
+    assert_eq!(precise::pow(0.0, 2.0), 0.0);
+    assert_eq!(precise::pow(4.0, 0.5), 2.0);
+}
+
+#[test]
+fn test_zeros() {
+    // The series around the zeros keep the error relative next to them.
+    for x0 in [1.0_f32, 2.0, reference::DIGAMMA_ROOT as f32] {
+        for i in -3..=3 {
+            let x = f32::from_bits((x0.to_bits() as i32 + i) as u32);
+            let ln_gamma = reference::ln_gamma(x as f64);
+            assert_ulp("ln_gamma", precise::ln_gamma(x), ln_gamma);
+            assert_ulp("digamma", precise::digamma(x), reference::digamma(x as f64));
+        }
+    }
+    assert_eq!(precise::ln_gamma(1.0), 0.0);
+    assert_eq!(precise::ln_gamma(2.0), 0.0);
+}
+
+#[test]
+fn test_special_values() {
+    assert_eq!(precise::exp(f32::INFINITY), f32::INFINITY);
+    assert_eq!(precise::exp(f32::NEG_INFINITY), 0.0);
+    assert_eq!(precise::exp(100.0), f32::INFINITY);
+    assert_eq!(precise::exp(0.0), 1.0);
+    assert_eq!(precise::pow2(-149.0), f32::from_bits(1));
+    assert_eq!(precise::ln(0.0), f32::NEG_INFINITY);
+    assert_eq!(precise::ln(1.0), 0.0);
+    assert!(precise::ln(-1.0).is_nan());
+    assert_eq!(precise::log2(f32::INFINITY), f32::INFINITY);
+    assert_eq!(precise::log2(f32::from_bits(1)), -149.0);
+    assert!(precise::sin(f32::INFINITY).is_nan());
+    assert!(precise::cos(f32::NAN).is_nan());
+    assert_eq!(precise::sinh(-0.0).to_bits(), (-0.0_f32).to_bits());
+    assert_eq!(precise::sinh(1000.0), f32::INFINITY);
+    assert_eq!(precise::tanh(-100.0), -1.0);
+    assert_eq!(precise::erf(f32::INFINITY), 1.0);
+    assert_eq!(precise::erfc(f32::INFINITY), 0.0);
+    assert_eq!(precise::erfc(f32::NEG_INFINITY), 2.0);
+    assert_eq!(precise::erf_inv(1.0), f32::INFINITY);
+    assert_eq!(precise::erf_inv(-1.0), f32::NEG_INFINITY);
+    assert!(precise::erf_inv(1.5).is_nan());
+    assert_eq!(precise::lambertw(0.0), 0.0);
+    assert_eq!(precise::bessel_i1_over_i0(0.0), 0.0);
+    assert_eq!(precise::exp_int_ei(0.0), f32::NEG_INFINITY);
+}
+
+#[test]
+fn test_more_accurate_than_fast() {
+    for x in [0.1_f32, 0.7, 1.3, 2.9] {
+        for f in ALL.iter().filter(|f| f.tier == Tier::Precise) {
+            let (lo, hi) = f.domain;
+            if x < lo || x > hi {
+                continue;
+            }
+            let fast = registry::get(Tier::Fast, f.name).unwrap();
+            let exact = (f.reference)(x as f64);
+            let precise_error = ((f.approx)(x) as f64 - exact).abs();
+            let fast_error = ((fast.approx)(x) as f64 - exact).abs();
+            assert!(
+                precise_error <= fast_error,
+                "{} at {}",
+                f.qualified_name(),
+                x
+            );
+        }
+    }
+}
@@ -0,0 +1,117 @@
+#![cfg(test)]
+
+extern crate fastapprox;
+extern crate special;
+extern crate statrs;
+
+use std::f64::consts::PI;
+
+use fastapprox::reference;
+use special::{Error, Gamma};
+use statrs::function::erf;
+
+fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+    let d = ((actual - expected) / expected.abs().max(1.0)).abs();
+    assert!(
+        d < tolerance,
+        "actual = {}, but expected = {}, Δ == {}",
+        actual,
+        expected,
+        d
+    );
+}
+
+#[test]
+fn test_erf() {
+    for &x in &[-6.0, -2.6, -1.0, -0.1, 0.0, 1e-3, 0.5, 2.4, 3.0, 5.0] {
+        assert_close(reference::erf(x), x.error(), 1e-14);
+        assert_close(reference::erfc(x), x.compl_error(), 1e-14);
+    }
+    for &x in &[3.0, 10.0, 26.0] {
+        let expected = x.compl_error();
+        assert!(((reference::erfc(x) - expected) / expected).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_erf_inv() {
+    for &x in &[-0.9999999, -0.9, -0.3, 1e-5, 0.5, 0.99, 0.99999994] {
+        assert_close(reference::erf(reference::erf_inv(x)), x, 1e-14);
+        assert_close(reference::erf_inv(x), erf::erf_inv(x), 1e-9);
+    }
+}
+
+#[test]
+fn test_gamma() {
+    for &x in &[1e-20, 1e-3, 0.5, 1.0, 2.5, 10.0, 171.0, 1e10] {
+        assert_close(reference::ln_gamma(x), Gamma::ln_gamma(x).0, 1e-13);
+        assert_close(reference::digamma(x), x.digamma(), 1e-13);
+    }
+}
+
+#[test]
+fn test_lambertw() {
+    for &x in &[-0.36, -0.1, 1e-10, 0.5, 3.0, 1e3, 1e30] {
+        let w = reference::lambertw(x);
+        assert_close(w * w.exp(), x, 1e-13);
+    }
+    for &x in &[-50.0, 0.0, 1.0, 10.0, 700.0, 1e30] {
+        let w = reference::lambertwexpx(x);
+        assert_close(w + w.ln(), x, 1e-13);
+    }
+}
+
+#[test]
+fn test_bessel() {
+    assert_close(reference::bessel_i(0, 0.0), 1.0, 1e-15);
+    assert_close(reference::bessel_i(0, 1.0), 1.2660658777520082, 1e-14);
+    assert_close(reference::bessel_i(1, -1.0), -0.5651591039924851, 1e-14);
+    // The asymptotic expansion used for large values agrees with the power series.
+    for &x in &[50.5, 60.0, 80.0] {
+        let q = 0.25 * x * x;
+        let (mut term, mut sum) = (1.0, 1.0);
+        for k in 1..500 {
+            term *= q / (k * k) as f64;
+            sum += term;
+        }
+        assert_close(reference::bessel_i(0, x) / sum, 1.0, 1e-13);
+    }
+    assert_close(reference::bessel_i1_over_i0(1e4), 0.999949998749906, 1e-12);
+}
+
+#[test]
+fn test_zeta() {
+    let pi2 = PI * PI;
+    assert_close(reference::zeta(2.0), pi2 / 6.0, 1e-14);
+    // Apéry's constant.
+    assert_close(reference::zeta(3.0), 1.2020569031595943, 1e-14);
+    assert_close(reference::zeta(4.0), pi2 * pi2 / 90.0, 1e-14);
+    assert_close(reference::zeta(1.5), 2.612375348685488, 1e-14);
+    // ζ(s, 1/2) = (2^s - 1) ζ(s) and ζ(s, 2) = ζ(s) - 1.
+    assert_close(reference::hurwitz_zeta(2.0, 0.5), pi2 / 2.0, 1e-14);
+    assert_close(
+        reference::hurwitz_zeta(3.0, 0.5),
+        7.0 * 1.2020569031595943,
+        1e-14,
+    );
+    assert_close(reference::hurwitz_zeta(2.0, 2.0), pi2 / 6.0 - 1.0, 1e-14);
+}
+
+#[test]
+fn test_exp_int() {
+    assert_close(reference::exp_int_e1(1.0), 0.21938393439552027, 1e-14);
+    assert_close(reference::exp_int_e1(0.01), 4.037929576538114, 1e-14);
+    let e1_10 = reference::exp_int_e1(10.0);
+    assert!((e1_10 / 4.156968929685324e-6 - 1.0).abs() < 1e-13);
+    assert_close(reference::exp_int_ei(1.0), 1.8951178163559368, 1e-14);
+    assert_close(reference::exp_int_ei(-1.0), -0.21938393439552027, 1e-14);
+    assert_close(reference::exp_int_ei(10.0), 2492.2289762418778, 1e-14);
+    assert_close(reference::exp_int_ei(50.0), 1.058563689713169e20, 1e-14);
+    // The root of Ei.
+    assert!(reference::exp_int_ei(0.3725074107813666).abs() < 1e-15);
+    // li vanishes at Soldner's constant.
+    assert!(reference::log_int(1.451369234883381).abs() < 1e-15);
+    assert_close(reference::log_int(2.0), 1.045163780117493, 1e-14);
+    assert_close(reference::log_int(10.0), 6.165599504787298, 1e-14);
+    assert_close(reference::log_int(1e6), 78627.54915946218, 1e-14);
+}
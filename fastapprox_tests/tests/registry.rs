@@ -0,0 +1,131 @@
+#![cfg(test)]
+
+extern crate fastapprox;
+extern crate fastapprox_tests;
+
+use std::collections::HashSet;
+
+use fastapprox::registry::{self, ErrorBound, Tier, ALL, BINARY};
+use fastapprox_tests::accuracy::{from_ordinal, ordinal};
+
+/// Distance between swept floats, odd so that it does not line up with the baseline sweep.
+const STRIDE: usize = 16411;
+
+#[test]
+fn test_names() {
+    let names: HashSet<String> = ALL.iter().map(|f| f.qualified_name()).collect();
+    assert_eq!(names.len(), ALL.len());
+    for triple in ALL.chunks(3) {
+        assert_eq!(triple[0].name, triple[1].name);
+        assert_eq!(triple[0].name, triple[2].name);
+        assert_eq!(
+            (triple[0].tier, triple[1].tier, triple[2].tier),
+            (Tier::Fast, Tier::Faster, Tier::Precise)
+        );
+    }
+    let info = registry::get(Tier::Fast, "erf").unwrap();
+    assert_eq!(info.qualified_name(), "fast::erf");
+    assert_eq!((info.approx)(0.5), fastapprox::fast::erf(0.5));
+    assert!(registry::get(Tier::Faster, "hurwitz_zeta").is_none());
+    let info = registry::get_binary(Tier::Faster, "hurwitz_zeta").unwrap();
+    assert_eq!(info.qualified_name(), "faster::hurwitz_zeta");
+    assert_eq!(
+        (info.approx)(2.0, 0.5),
+        fastapprox::faster::hurwitz_zeta(2.0, 0.5)
+    );
+    assert_eq!(Tier::Precise.to_string(), "precise");
+}
+
+#[test]
+fn test_binary_names() {
+    let names: HashSet<String> = BINARY.iter().map(|f| f.qualified_name()).collect();
+    assert_eq!(names.len(), BINARY.len());
+    for triple in BINARY.chunks(3) {
+        assert_eq!(triple[0].name, triple[1].name);
+        assert_eq!(triple[0].name, triple[2].name);
+        assert_eq!(
+            (triple[0].tier, triple[1].tier, triple[2].tier),
+            (Tier::Fast, Tier::Faster, Tier::Precise)
+        );
+        assert!(registry::get(Tier::Fast, triple[0].name).is_none());
+    }
+}
+
+#[test]
+fn test_error_bound() {
+    let bound = ErrorBound {
+        abs: 0.5,
+        rel: 0.25,
+    };
+    assert_eq!(bound.tolerance(-100.0), 25.5);
+    assert!(bound.holds(-0.5, 0.0));
+    assert!(!bound.holds(0.75, 0.0));
+    assert!(bound.holds(125.5, 100.0));
+    assert!(!bound.holds(126.0, 100.0));
+}
+
+#[test]
+fn test_domains() {
+    for f in ALL {
+        let (lo, hi) = f.domain;
+        assert!(lo < hi, "{}", f.qualified_name());
+        for x in [lo, hi, 0.75 * lo + 0.25 * hi] {
+            assert!((f.approx)(x).is_finite(), "{} at {}", f.qualified_name(), x);
+        }
+    }
+}
+
+#[test]
+fn test_error_bounds() {
+    for f in ALL {
+        let (lo, hi) = (ordinal(f.domain.0), ordinal(f.domain.1));
+        for i in (lo..=hi).step_by(STRIDE).chain([hi]) {
+            let x = from_ordinal(i);
+            let exact = (f.reference)(x as f64);
+            if !(exact as f32).is_finite() {
+                continue;
+            }
+            let actual = (f.approx)(x);
+            assert!(
+                f.error.holds(actual, exact),
+                "{}({}) = {}, but expected {} within {}",
+                f.qualified_name(),
+                x,
+                actual,
+                exact,
+                f.error.tolerance(exact)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_binary_error_bounds() {
+    for f in BINARY {
+        let (lo, hi) = (ordinal(f.domain.0), ordinal(f.domain.1));
+        for &y in f.fixed {
+            for i in (lo..=hi).step_by(STRIDE).chain([hi]) {
+                let x = from_ordinal(i);
+                let exact = (f.reference)(x as f64, y as f64);
+                assert!(
+                    (exact as f32).is_normal(),
+                    "{}({}, {})",
+                    f.qualified_name(),
+                    x,
+                    y
+                );
+                let actual = (f.approx)(x, y);
+                assert!(
+                    f.error.holds(actual, exact),
+                    "{}({}, {}) = {}, but expected {} within {}",
+                    f.qualified_name(),
+                    x,
+                    y,
+                    actual,
+                    exact,
+                    f.error.tolerance(exact)
+                );
+            }
+        }
+    }
+}
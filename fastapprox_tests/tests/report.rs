@@ -0,0 +1,100 @@
+#![cfg(test)]
+
+extern crate fastapprox;
+extern crate fastapprox_tests;
+
+use std::fs;
+
+use fastapprox::fast;
+use fastapprox::registry::{ErrorBound, FunctionInfo, Tier, ALL, BINARY};
+use fastapprox_tests::accuracy::Sweep;
+use fastapprox_tests::report::{
+    self, from_csv, regressions, to_csv, to_json, Record, BASELINE_INTERVALS, BASELINE_STRIDE,
+};
+
+const LN: FunctionInfo = FunctionInfo {
+    name: "ln",
+    tier: Tier::Fast,
+    approx: fast::ln,
+    reference: f64::ln,
+    domain: (0.5, 1e6),
+    error: ErrorBound::UNBOUNDED,
+};
+
+fn sweep_ln(approx: fn(f32) -> f32) -> Vec<Record> {
+    let sweep = Sweep {
+        stride: 1 << 10,
+        threads: 2,
+    };
+    report::sweep(&FunctionInfo { approx, ..LN }, &sweep, 3)
+}
+
+#[test]
+fn test_csv_round_trip() {
+    let records = sweep_ln(fast::ln);
+    assert_eq!(records.len(), 3);
+    let csv = to_csv(&records);
+    assert_eq!(csv.lines().count(), 4);
+    let parsed = from_csv(&csv).unwrap();
+    for (p, r) in parsed.iter().zip(&records) {
+        assert_eq!(p.function, r.function);
+        assert_eq!(p.tier, r.tier);
+        assert_eq!(p.interval.lo, r.interval.lo);
+        assert_eq!(p.interval.hi, r.interval.hi);
+        assert_eq!(p.interval.stats.count, r.interval.stats.count);
+        assert_eq!(p.interval.stats.max_ulp, r.interval.stats.max_ulp);
+        assert_eq!(p.interval.stats.max_rel, r.interval.stats.max_rel);
+        assert_eq!(p.interval.stats.max_abs, r.interval.stats.max_abs);
+    }
+    assert!(from_csv("function,tier\nln,fast\n").is_err());
+}
+
+#[test]
+fn test_json() {
+    let json = to_json(&sweep_ln(fast::ln));
+    assert!(json.starts_with("[\n  {\"function\": \"ln\", \"tier\": \"fast\", \"lo\": 5e-1,"));
+    assert_eq!(json.matches("\"histogram\": [").count(), 3);
+    assert!(json.ends_with("}\n]\n"));
+    assert_eq!(to_json(&[]), "[\n]\n");
+}
+
+#[test]
+fn test_regressions() {
+    let baseline = sweep_ln(fast::ln);
+    assert!(regressions(&baseline, &baseline).is_empty());
+    // A better approximation passes, a worse one does not.
+    assert!(regressions(&baseline, &sweep_ln(|x| (x as f64).ln() as f32)).is_empty());
+    let worse = regressions(&baseline, &sweep_ln(|x| fast::ln(x) * 1.001));
+    assert!(worse.iter().any(|r| r.contains("max rel")), "{:?}", worse);
+    // Missing or incomparable records are reported.
+    assert_eq!(regressions(&[], &baseline).len(), 1);
+    assert_eq!(regressions(&baseline[..2], &baseline).len(), 1);
+    let mut moved = baseline.clone();
+    moved[1].interval.lo = 0.75;
+    assert_eq!(regressions(&moved, &baseline).len(), 1);
+}
+
+/// Fails if an approximation got less accurate than recorded in `accuracy_baseline.csv`.
+///
+/// After an intended change of accuracy, regenerate the baseline as described in the
+/// documentation of the `fastapprox-accuracy` binary.
+#[test]
+fn test_baseline() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/accuracy_baseline.csv");
+    let baseline = from_csv(&fs::read_to_string(path).unwrap()).unwrap();
+    let sweep = Sweep {
+        stride: BASELINE_STRIDE,
+        ..Sweep::default()
+    };
+    let current: Vec<Record> = ALL
+        .iter()
+        .flat_map(|f| report::sweep(f, &sweep, BASELINE_INTERVALS))
+        .chain(BINARY.iter().flat_map(|f| {
+            f.fixed
+                .iter()
+                .flat_map(|&y| report::sweep_binary(f, y, &sweep, BASELINE_INTERVALS))
+        }))
+        .collect();
+    let problems = regressions(&baseline, &current);
+    assert!(problems.is_empty(), "\n{}", problems.join("\n"));
+}
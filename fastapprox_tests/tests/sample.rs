@@ -0,0 +1,195 @@
+#![cfg(test)]
+
+extern crate fastapprox;
+extern crate rand;
+extern crate statrs;
+
+use fastapprox::sample::{fast, faster};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use statrs::distribution::{Beta, ContinuousCDF, Exp, Gamma, Normal};
+
+const SAMPLES: usize = 20000;
+
+/// Kolmogorov–Smirnov critical value at the 0.1% level, scaled by `sqrt(SAMPLES)`.
+const KS_CRITICAL: f64 = 1.95;
+
+/// Allowance for the bias of the faster approximations on top of the critical value.
+const KS_FASTER_BIAS: f64 = 0.02;
+
+fn rng() -> StdRng {
+    StdRng::seed_from_u64(0x5eed)
+}
+
+/// Kolmogorov–Smirnov statistic of the samples against the given CDF.
+fn ks_statistic<F: Fn(f64) -> f64>(mut samples: Vec<f32>, cdf: F) -> f64 {
+    samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let n = samples.len() as f64;
+    samples
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| {
+            let f = cdf(x as f64);
+            (f - i as f64 / n).max((i + 1) as f64 / n - f)
+        })
+        .fold(0.0, f64::max)
+}
+
+fn argmax(x: &[f32]) -> usize {
+    (0..x.len())
+        .max_by(|&a, &b| x[a].partial_cmp(&x[b]).unwrap())
+        .unwrap()
+}
+
+fn check<F1, F2, F>(mut fast: F1, mut faster: F2, cdf: F)
+where
+    F1: FnMut(&mut StdRng) -> f32,
+    F2: FnMut(&mut StdRng) -> f32,
+    F: Fn(f64) -> f64,
+{
+    let critical = KS_CRITICAL / (SAMPLES as f64).sqrt();
+    let mut r = rng();
+    let d = ks_statistic((0..SAMPLES).map(|_| fast(&mut r)).collect(), &cdf);
+    assert!(d < critical, "fast: D = {}, critical = {}", d, critical);
+    let mut r = rng();
+    let d = ks_statistic((0..SAMPLES).map(|_| faster(&mut r)).collect(), &cdf);
+    let critical = critical + KS_FASTER_BIAS;
+    assert!(d < critical, "faster: D = {}, critical = {}", d, critical);
+}
+
+#[test]
+fn test_normal() {
+    let n = Normal::new(0.0, 1.0).unwrap();
+    check(fast::normal, faster::normal, |x| n.cdf(x));
+}
+
+#[test]
+fn test_normal_pair() {
+    let n = Normal::new(0.0, 1.0).unwrap();
+    check(
+        |r| fast::normal_pair(r).0,
+        |r| faster::normal_pair(r).0,
+        |x| n.cdf(x),
+    );
+    check(
+        |r| fast::normal_pair(r).1,
+        |r| faster::normal_pair(r).1,
+        |x| n.cdf(x),
+    );
+}
+
+#[test]
+fn test_exponential() {
+    for &rate in &[0.5_f32, 1.0, 20.0] {
+        let e = Exp::new(rate as f64).unwrap();
+        check(
+            |r| fast::exponential(r, rate),
+            |r| faster::exponential(r, rate),
+            |x| e.cdf(x),
+        );
+    }
+}
+
+#[test]
+fn test_gamma() {
+    for &(shape, rate) in &[(0.3_f32, 1.0_f32), (1.0, 2.0), (2.5, 0.5), (40.0, 3.0)] {
+        let g = Gamma::new(shape as f64, rate as f64).unwrap();
+        check(
+            |r| fast::gamma(r, shape, rate),
+            |r| faster::gamma(r, shape, rate),
+            |x| g.cdf(x),
+        );
+    }
+}
+
+#[test]
+fn test_beta() {
+    for &(a, b) in &[(0.5_f32, 0.5_f32), (2.0, 5.0), (10.0, 1.5)] {
+        let d = Beta::new(a as f64, b as f64).unwrap();
+        check(
+            |r| fast::beta(r, a, b),
+            |r| faster::beta(r, a, b),
+            |x| d.cdf(x),
+        );
+    }
+}
+
+#[test]
+fn test_dirichlet() {
+    // Each marginal of a Dirichlet variate follows a Beta distribution.
+    let alpha = [0.5_f32, 2.0, 3.5];
+    let total: f32 = alpha.iter().sum();
+    for i in 0..alpha.len() {
+        let d = Beta::new(alpha[i] as f64, (total - alpha[i]) as f64).unwrap();
+        let mut out = [0.0_f32; 3];
+        check(
+            |r| {
+                fast::dirichlet(r, &alpha, &mut out);
+                out[i]
+            },
+            |r| {
+                let mut out = [0.0_f32; 3];
+                faster::dirichlet(r, &alpha, &mut out);
+                out[i]
+            },
+            |x| d.cdf(x),
+        );
+    }
+    let mut out = [0.0_f32; 3];
+    fast::dirichlet(&mut rng(), &alpha, &mut out);
+    assert!((out.iter().sum::<f32>() - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_gumbel() {
+    for &(location, scale) in &[(0.0_f32, 1.0_f32), (-3.0, 0.5), (10.0, 4.0)] {
+        let cdf = |x: f64| (-(-(x - location as f64) / scale as f64).exp()).exp();
+        check(
+            |r| fast::gumbel(r, location, scale),
+            |r| faster::gumbel(r, location, scale),
+            cdf,
+        );
+    }
+}
+
+#[test]
+fn test_gumbel_softmax() {
+    // At a low temperature the hot index follows the softmax of the logits, which is checked
+    // with a chi-square test.
+    let logits = [1.0_f32, 0.0, -1.0, 2.0];
+    let norm: f64 = logits.iter().map(|&l| (l as f64).exp()).sum();
+    let expected: Vec<f64> = logits
+        .iter()
+        .map(|&l| (l as f64).exp() / norm * SAMPLES as f64)
+        .collect();
+    // Critical value of the chi-square distribution with 3 degrees of freedom at the 0.1% level.
+    const CHI2_CRITICAL: f64 = 16.27;
+
+    let mut r = rng();
+    let mut counts = [0_usize; 4];
+    let mut out = [0.0_f32; 4];
+    for _ in 0..SAMPLES {
+        fast::gumbel_softmax(&mut r, &logits, 0.01, &mut out);
+        assert!((out.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+        counts[argmax(&out)] += 1;
+    }
+    let chi2: f64 = counts
+        .iter()
+        .zip(&expected)
+        .map(|(&c, &e)| (c as f64 - e).powi(2) / e)
+        .sum();
+    assert!(chi2 < CHI2_CRITICAL, "chi2 = {}", chi2);
+
+    // The faster Gumbel noise is visibly biased, so only the frequencies are compared.
+    let mut r = rng();
+    let mut counts = [0_usize; 4];
+    for _ in 0..SAMPLES {
+        faster::gumbel_softmax(&mut r, &logits, 0.01, &mut out);
+        assert!((out.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+        counts[argmax(&out)] += 1;
+    }
+    for (&c, &e) in counts.iter().zip(&expected) {
+        let (freq, p) = (c as f64 / SAMPLES as f64, e / SAMPLES as f64);
+        assert!((freq - p).abs() < KS_FASTER_BIAS, "{} vs {}", freq, p);
+    }
+}
@@ -0,0 +1,198 @@
+#![cfg(test)]
+
+extern crate fastapprox;
+
+use fastapprox::softmax::{fast, faster};
+
+mod common;
+
+use common::{assert_abs_near, assert_all_abs_near, assert_near};
+
+const LOGITS: &[&[f32]] = &[
+    &[0.0],
+    &[1.0, 2.0, 3.0],
+    &[-5.0, -0.25, 0.0, 0.05, 2.0, 10.0],
+    &[1000.0, 1001.0, 999.0, 0.0],
+    &[-1000.0, -1001.0, -999.5],
+    &[3.0e4, -3.0e4, 1.0, f32::NEG_INFINITY],
+];
+
+const NEAR: f32 = 0.01;
+const FAR: f32 = 0.15;
+
+fn assert_log_near(actual: f32, expected: f32, tolerance: f32) {
+    if expected.is_infinite() {
+        assert_eq!(actual, expected);
+    } else {
+        assert_near(actual, expected, tolerance);
+    }
+}
+
+fn logsumexp(x: &[f32]) -> f64 {
+    let m = x.iter().fold(f64::NEG_INFINITY, |m, &v| m.max(v as f64));
+    m + x.iter().map(|&v| (v as f64 - m).exp()).sum::<f64>().ln()
+}
+
+fn softmax(x: &[f32], temperature: f64) -> Vec<f32> {
+    let scaled: Vec<f32> = x.iter().map(|&v| (v as f64 / temperature) as f32).collect();
+    let lse = logsumexp(&scaled);
+    scaled
+        .iter()
+        .map(|&v| (v as f64 - lse).exp() as f32)
+        .collect()
+}
+
+#[test]
+fn test_logsumexp() {
+    for x in LOGITS {
+        let e = logsumexp(x) as f32;
+        assert_near(fast::logsumexp(x), e, NEAR);
+        assert_near(faster::logsumexp(x), e, FAR);
+    }
+    assert_eq!(fast::logsumexp(&[]), f32::NEG_INFINITY);
+    assert_eq!(faster::logsumexp(&[]), f32::NEG_INFINITY);
+}
+
+#[test]
+fn test_softmax() {
+    for x in LOGITS {
+        let expected = softmax(x, 1.0);
+        let mut out = vec![0.0; x.len()];
+        fast::softmax(x, &mut out);
+        assert_all_abs_near(&out, &expected, NEAR);
+        assert_abs_near(out.iter().sum(), 1.0, 1e-5);
+        faster::softmax(x, &mut out);
+        assert_all_abs_near(&out, &expected, FAR);
+        assert_abs_near(out.iter().sum(), 1.0, 1e-5);
+    }
+}
+
+#[test]
+fn test_softmax_with_temperature() {
+    for &t in &[0.1_f32, 0.5, 2.0, 100.0] {
+        for x in LOGITS {
+            let expected = softmax(x, t as f64);
+            let mut out = vec![0.0; x.len()];
+            fast::softmax_with_temperature(x, t, &mut out);
+            assert_all_abs_near(&out, &expected, NEAR);
+            faster::softmax_with_temperature(x, t, &mut out);
+            assert_all_abs_near(&out, &expected, FAR);
+        }
+    }
+}
+
+#[test]
+fn test_log_softmax() {
+    for x in LOGITS {
+        let lse = logsumexp(x);
+        let expected: Vec<f32> = x.iter().map(|&v| (v as f64 - lse) as f32).collect();
+        let mut out = vec![0.0; x.len()];
+        fast::log_softmax(x, &mut out);
+        for (&a, &e) in out.iter().zip(&expected) {
+            assert_log_near(a, e, NEAR);
+        }
+        faster::log_softmax(x, &mut out);
+        for (&a, &e) in out.iter().zip(&expected) {
+            assert_log_near(a, e, FAR);
+        }
+    }
+}
+
+#[test]
+fn test_all_negative_infinity() {
+    let x = [f32::NEG_INFINITY; 3];
+    let mut out = [f32::NAN; 3];
+    assert_eq!(fast::logsumexp(&x), f32::NEG_INFINITY);
+    assert_eq!(faster::logsumexp(&x), f32::NEG_INFINITY);
+    fast::softmax(&x, &mut out);
+    assert_eq!(out, [0.0; 3]);
+    out = [f32::NAN; 3];
+    faster::softmax(&x, &mut out);
+    assert_eq!(out, [0.0; 3]);
+    out = [f32::NAN; 3];
+    fast::softmax_with_temperature(&x, 0.5, &mut out);
+    assert_eq!(out, [0.0; 3]);
+    out = [f32::NAN; 3];
+    faster::softmax_with_temperature(&x, 0.5, &mut out);
+    assert_eq!(out, [0.0; 3]);
+    fast::log_softmax(&x, &mut out);
+    assert_eq!(out, x);
+    out = [f32::NAN; 3];
+    faster::log_softmax(&x, &mut out);
+    assert_eq!(out, x);
+}
+
+#[test]
+fn test_online_logsumexp() {
+    for x in LOGITS {
+        let e = logsumexp(x) as f32;
+        let mut acc = fast::OnlineLogSumExp::new();
+        x.iter().for_each(|&v| acc.push(v));
+        assert_near(acc.finish(), e, NEAR);
+        let mut acc = faster::OnlineLogSumExp::new();
+        x.iter().for_each(|&v| acc.push(v));
+        assert_near(acc.finish(), e, FAR);
+    }
+    assert_eq!(fast::OnlineLogSumExp::new().finish(), f32::NEG_INFINITY);
+}
+
+#[test]
+fn test_online_logsumexp_merge() {
+    for x in LOGITS {
+        let e = logsumexp(x) as f32;
+        for split in 0..=x.len() {
+            let (mut left, mut right) =
+                (fast::OnlineLogSumExp::new(), fast::OnlineLogSumExp::new());
+            x[..split].iter().for_each(|&v| left.push(v));
+            x[split..].iter().for_each(|&v| right.push(v));
+            let mut merged = left;
+            merged.merge(&right);
+            assert_near(merged.finish(), e, NEAR);
+            right.merge(&left);
+            assert_near(right.finish(), e, NEAR);
+        }
+    }
+}
+
+#[test]
+fn test_online_softmax() {
+    for x in LOGITS {
+        let weights = softmax(x, 1.0);
+        let values: Vec<[f32; 2]> = (0..x.len())
+            .map(|i| [i as f32, 1.0 - 0.5 * i as f32])
+            .collect();
+        let expected: Vec<f32> = (0..2)
+            .map(|j| weights.iter().zip(&values).map(|(&w, v)| w * v[j]).sum())
+            .collect();
+        let mut out = [0.0_f32; 2];
+
+        let mut acc = fast::OnlineSoftmax::new(2);
+        for (&v, value) in x.iter().zip(&values) {
+            acc.push(v, value);
+        }
+        assert_near(acc.finish(&mut out), logsumexp(x) as f32, NEAR);
+        assert_all_abs_near(&out, &expected, NEAR * x.len() as f32);
+
+        let mut acc = faster::OnlineSoftmax::new(2);
+        for (&v, value) in x.iter().zip(&values) {
+            acc.push(v, value);
+        }
+        assert_near(acc.finish(&mut out), logsumexp(x) as f32, FAR);
+        assert_all_abs_near(&out, &expected, FAR * x.len() as f32);
+
+        let split = x.len() / 2;
+        let (mut left, mut right) = (fast::OnlineSoftmax::new(2), fast::OnlineSoftmax::new(2));
+        for (&v, value) in x[..split].iter().zip(&values) {
+            left.push(v, value);
+        }
+        for (&v, value) in x[split..].iter().zip(&values[split..]) {
+            right.push(v, value);
+        }
+        left.merge(&right);
+        left.finish(&mut out);
+        assert_all_abs_near(&out, &expected, NEAR * x.len() as f32);
+    }
+    let mut out = [1.0_f32; 2];
+    fast::OnlineSoftmax::new(2).finish(&mut out);
+    assert_eq!(out, [0.0, 0.0]);
+}
@@ -0,0 +1,92 @@
+#![cfg(test)]
+
+extern crate fastapprox;
+extern crate fastapprox_tests;
+
+use fastapprox::registry::Tier;
+use fastapprox::ulp::{self, Guarantee, GUARANTEES};
+use fastapprox_tests::accuracy::{ulp_error, Sweep};
+
+/// Checks the bound of `g` on every `stride`th float of its domain.
+fn assert_guarantee(g: &Guarantee, stride: u64) {
+    let f = g.info();
+    let sweep = Sweep {
+        stride,
+        ..Sweep::default()
+    };
+    let stats = sweep.run(g.approx, f.reference, f.domain.0, f.domain.1);
+    assert_eq!(stats.invalid, 0, "{}", g.name);
+    assert!(
+        stats.max_ulp_error.0 <= g.max_ulp,
+        "{}: {} ulp at {}",
+        g.name,
+        stats.max_ulp_error.0,
+        stats.max_ulp_error.1
+    );
+}
+
+#[test]
+fn test_names() {
+    for g in GUARANTEES {
+        let (function, level) = g.name.rsplit_once("_u").unwrap();
+        assert_eq!(function, g.function, "{}", g.name);
+        assert_eq!(
+            level.parse::<f64>().unwrap() / 10.0,
+            g.max_ulp,
+            "{}",
+            g.name
+        );
+        assert_ne!(g.tier, Tier::Faster, "{}", g.name);
+        let f = g.info();
+        assert_eq!(f.tier, g.tier);
+        for x in [0.1_f32, 0.5, 0.9] {
+            let x = f.domain.0 + x * (f.domain.1 - f.domain.0);
+            assert_eq!(
+                (g.approx)(x).to_bits(),
+                (f.approx)(x).to_bits(),
+                "{}",
+                g.name
+            );
+        }
+    }
+    // Every precise function has a name.
+    assert_eq!(
+        GUARANTEES
+            .iter()
+            .filter(|g| g.tier == Tier::Precise)
+            .count(),
+        fastapprox::registry::ALL
+            .iter()
+            .filter(|f| f.tier == Tier::Precise)
+            .count()
+    );
+}
+
+#[test]
+fn test_bounds() {
+    for g in GUARANTEES {
+        assert_guarantee(g, 12289);
+    }
+}
+
+/// Checks every bound on every float of its domain, which takes hours on one core. The
+/// `exhaustive` CI job runs it weekly, outside the checks that gate merges.
+#[test]
+#[ignore]
+fn test_bounds_exhaustive() {
+    for g in GUARANTEES {
+        assert_guarantee(g, 1);
+    }
+}
+
+#[test]
+fn test_ulp_error() {
+    assert_eq!(ulp_error(1.0, 1.0), 0.0);
+    assert_eq!(ulp_error(1.0 + f32::EPSILON, 1.0), 1.0);
+    // Half way between two floats, the rounded result is half a unit away.
+    assert_eq!(ulp_error(1.0, 1.0 + f32::EPSILON as f64 / 2.0), 0.5);
+    // The unit is that of the exact result, below a power of 2 too.
+    assert_eq!(ulp_error(1.0, 1.0 - f32::EPSILON as f64 / 2.0), 1.0);
+    assert_eq!(ulp_error(f32::from_bits(3), f32::from_bits(1) as f64), 2.0);
+    assert_eq!(ulp::exp_u10(0.0), 1.0);
+}
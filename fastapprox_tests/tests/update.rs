@@ -0,0 +1,157 @@
+#![cfg(test)]
+
+extern crate fastapprox;
+
+use fastapprox::update::{exact, fast, faster, Adam};
+
+mod common;
+
+use common::assert_all_near;
+
+const STEPS: usize = 50;
+const SIZE: usize = 16;
+
+const NEAR: f32 = 0.01;
+const FAR: f32 = 0.15;
+
+/// Deterministic gradients spanning several orders of magnitude, including zeros.
+// `is_multiple_of` needs Rust 1.87.
+#[allow(clippy::manual_is_multiple_of)]
+fn grads(step: usize) -> Vec<f32> {
+    (0..SIZE)
+        .map(|i| {
+            if i % 5 == 0 {
+                0.0
+            } else {
+                let sign = if (i + step) % 3 == 0 { -1.0 } else { 1.0 };
+                sign * 10.0_f32.powi(i as i32 % 7 - 3) * (1.0 + (step % 4) as f32 * 0.25)
+            }
+        })
+        .collect()
+}
+
+/// Runs the same update with all three tiers and compares the steps taken.
+///
+/// The optimizer state only depends on the gradients, so it stays identical across tiers and
+/// each step can be compared on its own.
+fn check<F1, F2, F3>(fast: F1, faster: F2, exact: F3)
+where
+    F1: Fn(&mut [f32], &[f32], &mut [f32], &mut [f32], usize),
+    F2: Fn(&mut [f32], &[f32], &mut [f32], &mut [f32], usize),
+    F3: Fn(&mut [f32], &[f32], &mut [f32], &mut [f32], usize),
+{
+    let mut state = [
+        (vec![0.0_f32; SIZE], vec![0.0; SIZE]),
+        (vec![0.0_f32; SIZE], vec![0.0; SIZE]),
+        (vec![0.0_f32; SIZE], vec![0.0; SIZE]),
+    ];
+    for step in 1..=STEPS {
+        let g = grads(step);
+        let mut deltas = [vec![0.0_f32; SIZE], vec![0.0; SIZE], vec![0.0; SIZE]];
+        let [s0, s1, s2] = &mut state;
+        let [d0, d1, d2] = &mut deltas;
+        exact(d0, &g, &mut s0.0, &mut s0.1, step);
+        fast(d1, &g, &mut s1.0, &mut s1.1, step);
+        faster(d2, &g, &mut s2.0, &mut s2.1, step);
+        assert_eq!(s1, s0);
+        assert_eq!(s2, s0);
+        assert_all_near(d1, d0, NEAR);
+        assert_all_near(d2, d0, FAR);
+    }
+}
+
+#[test]
+fn test_adagrad() {
+    check(
+        |w, g, a, _, _| fast::adagrad(w, g, a, 0.1, 1e-8),
+        |w, g, a, _, _| faster::adagrad(w, g, a, 0.1, 1e-8),
+        |w, g, a, _, _| exact::adagrad(w, g, a, 0.1, 1e-8),
+    );
+}
+
+#[test]
+fn test_rmsprop() {
+    check(
+        |w, g, v, _, _| fast::rmsprop(w, g, v, 0.01, 0.9, 1e-8),
+        |w, g, v, _, _| faster::rmsprop(w, g, v, 0.01, 0.9, 1e-8),
+        |w, g, v, _, _| exact::rmsprop(w, g, v, 0.01, 0.9, 1e-8),
+    );
+}
+
+#[test]
+fn test_adam() {
+    let params = Adam {
+        learning_rate: 0.01,
+        ..Adam::default()
+    };
+    check(
+        |w, g, m, v, t| fast::adam(w, g, m, v, t as u32, &params),
+        |w, g, m, v, t| faster::adam(w, g, m, v, t as u32, &params),
+        |w, g, m, v, t| exact::adam(w, g, m, v, t as u32, &params),
+    );
+}
+
+#[test]
+fn test_adam_eps_outside_sqrt() {
+    // After one step m̂ = g and v̂ = g², so the step is g / (|g| + eps) times the learning rate.
+    let params = Adam::default();
+    let g = 1e-6_f32;
+    let mut w = [0.0_f32];
+    exact::adam(&mut w, &[g], &mut [0.0], &mut [0.0], 1, &params);
+    assert_all_near(&w, &[-params.learning_rate * g / (g + params.eps)], 1e-9);
+}
+
+#[test]
+fn test_adagrad_eps_outside_sqrt() {
+    // After one step accum = g², so the step is g / (|g| + eps) times the learning rate.
+    let (g, eps) = (1e-6_f32, 1e-8_f32);
+    let mut w = [0.0_f32];
+    exact::adagrad(&mut w, &[g], &mut [0.0], 0.1, eps);
+    assert_all_near(&w, &[-0.1 * g / (g + eps)], 1e-9);
+}
+
+#[test]
+fn test_rmsprop_eps_outside_sqrt() {
+    // Starting from zero, one step with decay 0.75 gives mean_square = g² / 4.
+    let (g, eps) = (1e-6_f32, 1e-8_f32);
+    let mut w = [0.0_f32];
+    exact::rmsprop(&mut w, &[g], &mut [0.0], 0.01, 0.75, eps);
+    assert_all_near(&w, &[-0.01 * g / (0.5 * g + eps)], 1e-9);
+}
+
+#[test]
+fn test_power_t() {
+    for &p in &[0.0_f32, 0.25, 0.5, 1.0] {
+        check(
+            |w, g, a, _, _| fast::power_t(w, g, a, 0.1, p),
+            |w, g, a, _, _| faster::power_t(w, g, a, 0.1, p),
+            |w, g, a, _, _| exact::power_t(w, g, a, 0.1, p),
+        );
+    }
+}
+
+#[test]
+fn test_power_t_skips_untouched_weights() {
+    let mut w = [1.0_f32, 2.0];
+    let mut a = [0.0_f32, 0.0];
+    fast::power_t(&mut w, &[0.0, 0.0], &mut a, 0.1, 0.5);
+    assert_eq!(w, [1.0, 2.0]);
+    faster::power_t(&mut w, &[0.0, 0.0], &mut a, 0.1, 0.5);
+    assert_eq!(w, [1.0, 2.0]);
+}
+
+#[test]
+fn test_adagrad_matches_power_t() {
+    let g = grads(1);
+    let (mut w1, mut a1) = (vec![0.0_f32; SIZE], vec![0.0; SIZE]);
+    let (mut w2, mut a2) = (vec![0.0_f32; SIZE], vec![0.0; SIZE]);
+    exact::adagrad(&mut w1, &g, &mut a1, 0.1, 1e-30);
+    exact::power_t(&mut w2, &g, &mut a2, 0.1, 0.5);
+    assert_all_near(&w1, &w2, 1e-6);
+}
+
+#[test]
+#[should_panic]
+fn test_length_mismatch() {
+    fast::adagrad(&mut [0.0; 2], &[0.0; 3], &mut [0.0; 2], 0.1, 1e-8);
+}